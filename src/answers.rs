@@ -0,0 +1,348 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::{fs, io};
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::Answer;
+
+/// Why a submitted answer was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rejection {
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong
+}
+
+impl Rejection {
+    const ALL: [Rejection; 3] = [Rejection::TooHigh, Rejection::TooLow, Rejection::Wrong];
+
+    /// The suffix on `partN` naming this kind of guess in the file.
+    fn key(self) -> &'static str {
+        match self {
+            Rejection::TooHigh => "too_high",
+            Rejection::TooLow => "too_low",
+            Rejection::Wrong => "wrong"
+        }
+    }
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key().replace('_', " "))
+    }
+}
+
+/// The known correct answers, and the wrong guesses made along the way, read from a TOML file
+/// with a table per day:
+///
+/// ```toml
+/// [day01]
+/// part1 = 280
+/// part2 = 1797
+/// part2_too_low = [1000, 1500]
+///
+/// [day11]
+/// part1 = "hxbxxyzz"
+/// part2_wrong = ["hxbxxzaa"]
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, usize), Answer>,
+    rejected: BTreeMap<(u8, usize, Rejection), Vec<Answer>>
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers, AocError> {
+        let lines = finish(input, parse::document(input))?;
+
+        let mut answers = Answers::default();
+        let mut day = None;
+        for (i, line) in lines.into_iter().enumerate() {
+            let day = match line {
+                parse::Line::Table(d) => {
+                    day = Some(d);
+                    continue;
+                },
+                parse::Line::Blank => continue,
+                _ => day.ok_or_else(|| AocError::Parse {
+                    line: i + 1,
+                    column: 1,
+                    message: "expected a [dayNN] table before the first answer".to_owned()
+                })?
+            };
+
+            match line {
+                parse::Line::Answer(part, answer) => answers.set(day, part, answer),
+                parse::Line::Rejected(part, rejection, guesses) => {
+                    for guess in guesses {
+                        answers.reject(day, part, guess, rejection);
+                    }
+                },
+                parse::Line::Table(_) | parse::Line::Blank => unreachable!()
+            }
+        }
+
+        Ok(answers)
+    }
+
+    /// Reads the answers at `path`, treating a missing file as having none recorded.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("could not read {}: {}", path.display(), e))
+        }
+    }
+
+    /// Writes the answers to `path`.  Comments in the file aren't kept.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string()).map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: u8, part: usize) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn set(&mut self, day: u8, part: usize, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    /// Records a guess that was rejected.
+    pub fn reject(&mut self, day: u8, part: usize, guess: Answer, rejection: Rejection) {
+        let guesses = self.rejected.entry((day, part, rejection)).or_default();
+        if !guesses.iter().any(|g| matches(g, &guess)) {
+            guesses.push(guess);
+        }
+    }
+
+    /// Whether `answer` is known to be wrong: because it was guessed before, or because it's at
+    /// least a guess that was too high, or at most one that was too low.
+    pub fn rejected(&self, day: u8, part: usize, answer: &Answer) -> Option<Rejection> {
+        let guesses = |rejection| self.rejected.get(&(day, part, rejection)).into_iter().flatten();
+
+        if let Some(rejection) = Rejection::ALL.into_iter().find(|&r| guesses(r).any(|g| matches(g, answer))) {
+            return Some(rejection);
+        }
+
+        let Answer::Number(n) = *answer else { return None };
+        let number = |guess: &Answer| match *guess {
+            Answer::Number(g) => Some(g),
+            Answer::Text(_) => None
+        };
+        if guesses(Rejection::TooHigh).filter_map(number).any(|high| n >= high) {
+            Some(Rejection::TooHigh)
+        }
+        else if guesses(Rejection::TooLow).filter_map(number).any(|low| n <= low) {
+            Some(Rejection::TooLow)
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let days: BTreeSet<u8> = self.answers.keys().map(|&(day, _)| day)
+            .chain(self.rejected.keys().map(|&(day, _, _)| day))
+            .collect();
+
+        for (i, &day) in days.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[day{day:02}]")?;
+
+            for part in 1..=2 {
+                if let Some(answer) = self.get(day, part) {
+                    writeln!(f, "part{part} = {}", toml_value(answer))?;
+                }
+                for rejection in Rejection::ALL {
+                    if let Some(guesses) = self.rejected.get(&(day, part, rejection)) {
+                        let guesses: Vec<String> = guesses.iter().map(toml_value).collect();
+                        writeln!(f, "part{part}_{} = [{}]", rejection.key(), guesses.join(", "))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn toml_value(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(s) => format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Whether a computed answer agrees with the recorded one.  Compared as text, so that `"123"` in
+/// the file matches a numeric answer.
+pub fn matches(expected: &Answer, actual: &Answer) -> bool {
+    expected.to_string() == actual.to_string()
+}
+
+mod parse {
+    use nom::branch::alt;
+    use nom::bytes::complete::{escaped_transform, is_not, tag};
+    use nom::character::complete::{char, line_ending, multispace0, not_line_ending, one_of, space0};
+    use nom::combinator::{cut, map, opt, value, verify};
+    use nom::error::context;
+    use nom::multi::separated_list0;
+    use nom::sequence::{delimited, pair, preceded, terminated};
+    use crate::parse::{signed, unsigned, IResult};
+    use crate::solution::Answer;
+    use super::Rejection;
+
+    pub enum Line {
+        Table(u8),
+        Answer(usize, Answer),
+        Rejected(usize, Rejection, Vec<Answer>),
+        Blank
+    }
+
+    fn table(input: &str) -> IResult<&str, u8> {
+        context(
+            "[dayNN] table",
+            delimited(
+                tag("[day"),
+                cut(verify(unsigned, |day| (1..=25).contains(day))),
+                cut(char(']'))
+            )
+        )(input)
+    }
+
+    fn string(input: &str) -> IResult<&str, String> {
+        delimited(
+            char('"'),
+            map(
+                opt(escaped_transform(
+                    is_not("\"\\"),
+                    '\\',
+                    alt((value("\\", char('\\')), value("\"", char('"'))))
+                )),
+                Option::unwrap_or_default
+            ),
+            char('"')
+        )(input)
+    }
+
+    fn answer(input: &str) -> IResult<&str, Answer> {
+        context(
+            "answer",
+            alt((
+                map(string, Answer::Text),
+                map(signed::<i128>, Answer::Number)
+            ))
+        )(input)
+    }
+
+    fn answers(input: &str) -> IResult<&str, Vec<Answer>> {
+        context(
+            "list of answers",
+            delimited(
+                char('['),
+                delimited(multispace0, separated_list0(delimited(multispace0, char(','), multispace0), answer), multispace0),
+                char(']')
+            )
+        )(input)
+    }
+
+    fn rejection(input: &str) -> IResult<&str, Rejection> {
+        alt((
+            value(Rejection::TooHigh, tag("_too_high")),
+            value(Rejection::TooLow, tag("_too_low")),
+            value(Rejection::Wrong, tag("_wrong"))
+        ))(input)
+    }
+
+    fn entry(input: &str) -> IResult<&str, Line> {
+        let (input, part) = preceded(tag("part"), map(one_of("12"), |c| if c == '1' { 1 } else { 2 }))(input)?;
+        let (input, rejection) = opt(rejection)(input)?;
+        let (input, _) = delimited(space0, char('='), space0)(input)?;
+
+        match rejection {
+            None => context("part answer", map(cut(answer), |a| Line::Answer(part, a)))(input),
+            Some(r) => context("rejected answers", map(cut(answers), |a| Line::Rejected(part, r, a)))(input)
+        }
+    }
+
+    fn line(input: &str) -> IResult<&str, Line> {
+        delimited(
+            space0,
+            map(
+                opt(alt((map(table, Line::Table), entry))),
+                |line| line.unwrap_or(Line::Blank)
+            ),
+            pair(space0, opt(pair(char('#'), not_line_ending)))
+        )(input)
+    }
+
+    pub fn document(input: &str) -> IResult<&str, Vec<Line>> {
+        terminated(separated_list0(line_ending, line), opt(line_ending))(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<(), AocError> {
+        let answers = Answers::parse(
+            "# checked by hand\n[day01]\npart1 = 280\npart2 = -3  # odd\n\n[day11]\npart1 = \"hx\\\"bx\"\n"
+        )?;
+
+        assert_eq!(answers.get(1, 1), Some(&280.into()));
+        assert_eq!(answers.get(1, 2), Some(&(-3).into()));
+        assert_eq!(answers.get(11, 1), Some(&"hx\"bx".into()));
+        assert_eq!(answers.get(11, 2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("[day01]\npart1 = oops\n"),
+            Err(AocError::Parse { line: 2, column: 9, message: "expected answer in part answer, found `oops`".to_owned() })
+        );
+        assert!(Answers::parse("[day26]\n").is_err());
+        assert!(Answers::parse("part1 = 3\n").is_err());
+    }
+
+    #[test]
+    fn test_rejected() -> Result<(), AocError> {
+        let mut answers = Answers::parse("[day01]\npart1_too_high = [500]\npart1_too_low = [100, 200]\npart2_wrong = [\"abc\"]\n")?;
+        answers.reject(1, 1, 300.into(), Rejection::Wrong);
+
+        assert_eq!(answers.rejected(1, 1, &300.into()), Some(Rejection::Wrong));
+        assert_eq!(answers.rejected(1, 1, &600.into()), Some(Rejection::TooHigh));
+        assert_eq!(answers.rejected(1, 1, &150.into()), Some(Rejection::TooLow));
+        assert_eq!(answers.rejected(1, 1, &250.into()), None);
+        assert_eq!(answers.rejected(1, 2, &"abc".into()), Some(Rejection::Wrong));
+        assert_eq!(answers.rejected(2, 1, &600.into()), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), AocError> {
+        let mut answers = Answers::default();
+        answers.set(11, 1, "hx\"bx".into());
+        answers.set(1, 1, 280.into());
+        answers.reject(1, 2, 10.into(), Rejection::TooLow);
+        answers.reject(1, 2, 20.into(), Rejection::TooLow);
+
+        let text = answers.to_string();
+        assert_eq!(text, "[day01]\npart1 = 280\npart2_too_low = [10, 20]\n\n[day11]\npart1 = \"hx\\\"bx\"\n");
+        assert_eq!(Answers::parse(&text)?, answers);
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches(&"123".into(), &123.into()));
+        assert!(!matches(&"abc".into(), &"abd".into()));
+    }
+}
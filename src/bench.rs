@@ -0,0 +1,88 @@
+use std::time::Duration;
+use crate::days::Day;
+use crate::error::AocError;
+
+/// Summary statistics over the timings of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let mid = runs / 2;
+        let median = if runs.is_multiple_of(2) { (sorted[mid - 1] + sorted[mid]) / 2 } else { sorted[mid] };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Stats {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt())
+        }
+    }
+}
+
+/// Timings of one day: parsing, then each requested part.
+pub struct Bench {
+    pub parse: Stats,
+    pub parts: Vec<(usize, Stats)>
+}
+
+/// Runs `parts` of `day` on `input` `warmup` times without measuring, then `runs` times (at least
+/// once) collecting timings.  Fails on the first error parsing or solving.
+pub fn bench(day: &Day, input: &str, parts: &[usize], warmup: usize, runs: usize) -> Result<Bench, AocError> {
+    let runs = runs.max(1);
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut part_times = vec![Vec::with_capacity(runs); parts.len()];
+
+    for i in 0..warmup + runs {
+        let run = (day.run)(input, parts)?;
+        if i < warmup {
+            continue;
+        }
+
+        parse_times.push(run.parse_time);
+        for (times, part) in part_times.iter_mut().zip(run.parts) {
+            part.answer?;
+            times.push(part.time);
+        }
+    }
+
+    Ok(Bench {
+        parse: Stats::from_samples(&parse_times),
+        parts: parts.iter().copied()
+            .zip(part_times.iter().map(|times| Stats::from_samples(times)))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+}
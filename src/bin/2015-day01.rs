@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day01::Day01;
+
+build_main!(2015, 1, Day01);
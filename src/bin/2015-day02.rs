@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day02::Day02;
+
+build_main!(2015, 2, Day02);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day03::Day03;
+
+build_main!(2015, 3, Day03);
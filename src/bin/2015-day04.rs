@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day04::Day04;
+
+build_main!(2015, 4, Day04);
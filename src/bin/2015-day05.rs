@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day05::Day05;
+
+build_main!(2015, 5, Day05);
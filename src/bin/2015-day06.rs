@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day06::Day06;
+
+build_main!(2015, 6, Day06);
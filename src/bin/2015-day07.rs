@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day07::Day07;
+
+build_main!(2015, 7, Day07);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day08::Day08;
+
+build_main!(2015, 8, Day08);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day09::Day09;
+
+build_main!(2015, 9, Day09);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day10::Day10;
+
+build_main!(2015, 10, Day10);
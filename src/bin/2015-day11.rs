@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day11::Day11;
+
+build_main!(2015, 11, Day11);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day12::Day12;
+
+build_main!(2015, 12, Day12);
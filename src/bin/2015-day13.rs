@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day13::Day13;
+
+build_main!(2015, 13, Day13);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day14::Day14;
+
+build_main!(2015, 14, Day14);
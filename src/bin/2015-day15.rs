@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day15::Day15;
+
+build_main!(2015, 15, Day15);
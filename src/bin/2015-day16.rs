@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day16::Day16;
+
+build_main!(2015, 16, Day16);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day17::Day17;
+
+build_main!(2015, 17, Day17);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day18::Day18;
+
+build_main!(2015, 18, Day18);
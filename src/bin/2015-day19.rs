@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day19::Day19;
+
+build_main!(2015, 19, Day19);
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day20::Day20;
+
+build_main!(2015, 20, Day20);
@@ -0,0 +1,39 @@
+use std::process::exit;
+use std::time::Instant;
+use adventofcode::days::y2015::day21::{cheapest_win, dearest_loss, Day21};
+use adventofcode::error::AocError;
+use adventofcode::solution::Solution;
+use adventofcode::{print_parse, print_part, read_input};
+
+/// Solves the puzzle as usual, then prints the fights behind each answer hit by hit.
+fn main() {
+    let input = read_input(2015, 21).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        exit(1);
+    });
+
+    let start = Instant::now();
+    let boss = Day21::parse(&input).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        exit(1);
+    });
+    print_parse(start.elapsed());
+
+    let mut solved = true;
+    let mut fights = Vec::new();
+    let searches = [(1, "cheapest win", cheapest_win as fn(&_) -> _), (2, "most expensive loss", dearest_loss)];
+    for (part, what, search) in searches {
+        let start = Instant::now();
+        let loadout = search(&boss);
+        let answer = loadout.map(|player| player.cost().into()).ok_or(AocError::NoSolution);
+        solved &= print_part(part, &answer, start.elapsed());
+        fights.extend(loadout.map(|player| (part, what, player)));
+    }
+
+    for (part, what, player) in fights {
+        print!("\nPart {part}, the {what}, {} gold for {player}:\n{}", player.cost(), player.fight(&boss));
+    }
+    if !solved {
+        exit(1);
+    }
+}
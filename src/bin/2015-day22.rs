@@ -0,0 +1,143 @@
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process::exit;
+use std::time::Instant;
+use adventofcode::days::y2015::day22::{cheapest_win, Boss, Day22, Game, Rules};
+use adventofcode::error::AocError;
+use adventofcode::solution::Solution;
+use adventofcode::{print_parse, print_part, read_input_from};
+
+const USAGE: &str = "\
+usage: 2015-day22 [<input>]
+       2015-day22 --play [--hard] [<input>]";
+
+const HELP: &str = "\
+Each round, cast a spell by its name or number.  Other commands:
+  spells   list the spells
+  best     show the cheapest way to win from here
+  undo     take back the last round
+  quit     stop";
+
+/// Solves the puzzle as usual, then prints the cheapest win behind each answer round by round.
+/// With `--play`, fights the boss interactively instead.
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let play = take_flag(&mut args, "--play");
+    let hard_mode = take_flag(&mut args, "--hard");
+    if args.len() > 1 || args.first().is_some_and(|arg| arg.starts_with("--")) || (hard_mode && !play) {
+        eprintln!("{USAGE}");
+        exit(2);
+    }
+
+    let input = read_input_from(args.first().map(String::as_str), 2015, 22).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        exit(1);
+    });
+
+    let start = Instant::now();
+    let boss = Day22::parse(&input).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        exit(1);
+    });
+    let parse_time = start.elapsed();
+
+    if play {
+        play_game(&boss, Rules::standard(hard_mode));
+        return;
+    }
+
+    print_parse(parse_time);
+    let mut solved = true;
+    let mut battles = Vec::new();
+    for (part, hard_mode) in [(1, false), (2, true)] {
+        let start = Instant::now();
+        let answer = match cheapest_win(&boss, &Rules::standard(hard_mode)) {
+            Ok(Some(battle)) => {
+                let mana_spent = battle.mana_spent();
+                battles.push((part, battle));
+                Ok(mana_spent.into())
+            },
+            Ok(None) => Err(AocError::NoSolution),
+            Err(err) => Err(err)
+        };
+        solved &= print_part(part, &answer, start.elapsed());
+    }
+
+    for (part, battle) in battles {
+        print!("\nPart {part}:\n{battle}");
+    }
+    if !solved {
+        exit(1);
+    }
+}
+
+/// Removes `flag` from `args`, giving whether it was there.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let before = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != before
+}
+
+fn print_spells(game: &Game) {
+    for (i, spell) in game.rules().spellbook.spells().iter().enumerate() {
+        println!("  {}. {} ({} mana)", i + 1, spell.name, spell.cost);
+    }
+}
+
+/// Reads commands from stdin a line at a time, printing the state of the fight after each round.
+fn play_game(boss: &Boss, rules: Rules) {
+    let mut game = Game::new(boss, rules);
+    println!("{HELP}\n");
+    print_spells(&game);
+    print!("\n{game}");
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("> ");
+        let _ = io::stdout().flush();
+        let Some(Ok(line)) = lines.next() else { break };
+
+        match line.trim() {
+            "" => continue,
+            "quit" | "q" => break,
+            "help" | "?" => {
+                println!("{HELP}");
+                continue;
+            },
+            "spells" | "s" => {
+                print_spells(&game);
+                continue;
+            },
+            "best" | "b" => {
+                match game.best_continuation() {
+                    Ok(Some(battle)) => print!("{} more mana to win:\n{battle}", battle.mana_spent()),
+                    Ok(None) => println!("there's no way to win from here"),
+                    Err(err) => println!("{err}")
+                }
+                continue;
+            },
+            "undo" | "u" => {
+                if !game.undo() {
+                    println!("nothing to undo");
+                    continue;
+                }
+            },
+            spell => {
+                let spellbook = &game.rules().spellbook;
+                let i = match spell.parse::<usize>() {
+                    Ok(n) if (1..=spellbook.spells().len()).contains(&n) => Some(n - 1),
+                    _ => spellbook.find(spell)
+                };
+                let Some(i) = i else {
+                    println!("no spell or command {spell:?}; try help");
+                    continue;
+                };
+                if let Err(err) = game.play(i) {
+                    println!("{err}");
+                    continue;
+                }
+            }
+        }
+        print!("{game}");
+    }
+}
@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day23::Day23;
+
+build_main!(2015, 23, Day23);
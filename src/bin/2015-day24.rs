@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day24::Day24;
+
+build_main!(2015, 24, Day24);
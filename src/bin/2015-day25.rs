@@ -0,0 +1,4 @@
+use adventofcode::build_main;
+use adventofcode::days::y2015::day25::Day25;
+
+build_main!(2015, 25, Day25);
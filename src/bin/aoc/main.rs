@@ -0,0 +1,636 @@
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use output::{format_duration, print_records, take_format, Field, Format, Record};
+use pool::{Job, Outcome};
+use adventofcode::answers::{self, Answers};
+use adventofcode::cancel;
+use adventofcode::bench::{self, Bench, Stats};
+use adventofcode::days::{self, Day};
+use adventofcode::error::AocError;
+use adventofcode::fetch::{self, Client, Verdict};
+use adventofcode::solution::{Answer, Run};
+use adventofcode::{input_root, read_file, Inputs};
+
+mod new;
+mod output;
+mod pool;
+
+const USAGE: &str = "Usage: aoc run <days> [--part <1|2>] [--jobs <n>] [--timeout <secs>]
+               [--inputs <dir>] [--profile <names>] [--format <format>]
+       aoc bench <days> [--part <1|2>] [--runs <n>] [--warmup <n>] [--parse]
+                 [--inputs <dir>] [--profile <name>] [--format <format>]
+       aoc verify [<days>] [--part <1|2>] [--jobs <n>] [--timeout <secs>]
+                  [--inputs <dir>] [--profile <names>]
+       aoc fetch <days> [--inputs <dir>] [--profile <name>]
+       aoc submit <day> <part> [--timeout <secs>] [--inputs <dir>] [--profile <name>]
+       aoc new <day>
+
+Every command also takes --year <year>, defaulting to the latest solved year.
+
+  <days>      `all`, a single day (`7`), a range (`1-5`), or a comma-separated
+              list of either (`1,3,10-12`)
+  --jobs      parts to solve at once (default: one per CPU); use 1 for the
+              most reliable timings
+  --timeout   give up on a part after this long
+  --inputs    the input root (default: $AOC_INPUTS, or `input`), holding
+              <year>/dayNN.txt files and <year>/answers.toml
+  --profile   use the inputs in these subdirectories of the root instead, one
+              table per profile: a comma-separated list of names, or `all`
+  --runs      timed runs of each day (default 10)
+  --warmup    untimed runs before those (default 1)
+  --parse     also report parse times, as part `parse`
+  --format    `table` (the default), `json` (an object per line), `csv` or `tsv`;
+              times are in nanoseconds
+
+`fetch` downloads inputs that aren't there yet from $AOC_BASE_URL (default
+https://adventofcode.com), logged in with the session cookie in $AOC_SESSION.
+`submit` solves a part and sends the answer there, recording the verdict in
+answers.toml; answers already known to be wrong aren't sent again.
+
+`new` adds a day to this crate from a template: its module under
+src/days/y<year> with a test stub, its binary, its entry in the runner, and an
+empty input file.
+
+`verify` checks answers against answers.toml, which has a table per day:
+
+  [day01]
+  part1 = 280
+  part2 = \"hxbxxyzz\"
+  part2_too_low = [1000]       # also part2_too_high and part2_wrong";
+
+struct RunArgs {
+    year: u16,
+    days: Vec<&'static Day>,
+    part: Option<usize>,
+    jobs: Option<usize>,
+    timeout: Option<Duration>,
+    /// The input root or the chosen profiles in it, each run separately.
+    inputs: Vec<Inputs>
+}
+
+fn parse_days(year: u16, spec: &str) -> Result<Vec<&'static Day>, String> {
+    if spec == "all" {
+        return Ok(days::all().filter(|day| day.year == year).collect());
+    }
+
+    let mut result = Vec::new();
+    for item in spec.split(',') {
+        let (from, to) = item.split_once('-').unwrap_or((item, item));
+        let from = from.parse::<u8>().map_err(|_| format!("invalid day: {from}"))?;
+        let to = to.parse::<u8>().map_err(|_| format!("invalid day: {to}"))?;
+
+        for day in from..=to {
+            let day = days::get(year, day).ok_or_else(|| format!("no solution for {year} day {day}"))?;
+            result.push(day);
+        }
+    }
+
+    Ok(result)
+}
+
+fn parse_part(value: &str) -> Result<usize, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part: {value}"))
+    }
+}
+
+fn option_value<'a>(name: &str, args: &mut impl Iterator<Item=&'a String>) -> Result<&'a str, String> {
+    args.next().map(String::as_str).ok_or_else(|| format!("{name} needs a value"))
+}
+
+/// The inputs to run against: the root itself, or the named profiles in it (`all` for every one).
+fn resolve_inputs(root: Option<&str>, profiles: Option<&str>) -> Result<Vec<Inputs>, String> {
+    let root = input_root(root);
+
+    match profiles {
+        None => Ok(vec![Inputs::root(&root)]),
+        Some("all") => {
+            let profiles = Inputs::profiles(&root).map_err(|e| e.to_string())?;
+            if profiles.is_empty() {
+                return Err(format!("no profiles in {}", root.display()));
+            }
+            Ok(profiles)
+        },
+        Some(names) => names.split(',')
+            .map(|name| {
+                let inputs = Inputs::profile(&root, name);
+                if inputs.dir.is_dir() {
+                    Ok(inputs)
+                }
+                else {
+                    Err(format!("no profile {name} in {}", root.display()))
+                }
+            })
+            .collect()
+    }
+}
+
+/// Parses the days and options, using `default_days` if no days are given.
+fn parse_run_args(args: &[String], default_days: Option<&str>) -> Result<RunArgs, String> {
+    let mut year = None;
+    let mut days = None;
+    let mut part = None;
+    let mut jobs = None;
+    let mut timeout = None;
+    let mut root = None;
+    let mut profiles = None;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => {
+                let value = option_value(arg, &mut args)?;
+                year = Some(value.parse::<u16>().map_err(|_| format!("invalid {arg}: {value}"))?);
+            },
+            "--part" | "-p" => part = Some(parse_part(option_value(arg, &mut args)?)?),
+            "--jobs" | "-j" => {
+                let value = option_value(arg, &mut args)?;
+                match value.parse() {
+                    Ok(n) if n > 0 => jobs = Some(n),
+                    _ => return Err(format!("invalid {arg}: {value}"))
+                }
+            },
+            "--timeout" => {
+                let value = option_value(arg, &mut args)?;
+                let secs = value.parse().ok().and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+                timeout = Some(secs.ok_or_else(|| format!("invalid {arg}: {value}"))?);
+            },
+            "--inputs" => root = Some(option_value(arg, &mut args)?),
+            "--profile" => profiles = Some(option_value(arg, &mut args)?),
+            spec if days.is_none() => days = Some(spec),
+            other => return Err(format!("unexpected argument: {other}"))
+        }
+    }
+
+    let year = match year {
+        Some(year) => year,
+        None => days::years().last().ok_or("no days are solved yet")?
+    };
+    let days = match days.or(default_days) {
+        Some(spec) => parse_days(year, spec)?,
+        None => return Err("no days given".to_owned())
+    };
+    let inputs = resolve_inputs(root, profiles)?;
+    Ok(RunArgs { year, days, part, jobs, timeout, inputs })
+}
+
+struct BenchArgs {
+    run: RunArgs,
+    runs: usize,
+    warmup: usize,
+    parse: bool,
+    format: Format
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let (args, format) = take_format(args)?;
+    let mut run_args = Vec::new();
+    let mut runs = 10;
+    let mut warmup = 1;
+    let mut parse = false;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" | "--warmup" => {
+                let value = option_value(arg, &mut args)?;
+                let n = value.parse().map_err(|_| format!("invalid {arg}: {value}"))?;
+                if arg == "--runs" { runs = n } else { warmup = n }
+            },
+            "--parse" => parse = true,
+            _ => run_args.push(arg.clone())
+        }
+    }
+
+    if runs == 0 {
+        return Err("--runs must be at least 1".to_owned());
+    }
+
+    let run = parse_run_args(&run_args, None)?;
+    if run.jobs.is_some() || run.timeout.is_some() {
+        return Err("bench runs one day at a time, without a timeout".to_owned());
+    }
+    if run.inputs.len() > 1 {
+        return Err("bench runs against one profile at a time".to_owned());
+    }
+
+    Ok(BenchArgs { run, runs, warmup, parse, format })
+}
+
+struct Row {
+    day: u8,
+    part: usize,
+    outcome: Result<Answer, String>,
+    /// Only set on the first row of each day, since the input is parsed once for all parts.
+    parse_time: Option<Duration>,
+    time: Duration
+}
+
+/// How long past `--timeout` a part that hasn't noticed its deadline is left running before it's
+/// abandoned.
+const TIMEOUT_GRACE: Duration = Duration::from_millis(500);
+
+/// Runs the requested parts of each day on `inputs`, one row per part in order.  Each part is a
+/// separate job on the thread pool, parsing the input for itself.
+fn solve(args: &RunArgs, inputs: &Inputs) -> Vec<Row> {
+    let mut jobs: Vec<Job<Result<Run, String>>> = Vec::new();
+    let mut labels = Vec::new();
+
+    for &day in &args.days {
+        let input: Result<Arc<str>, String> = read_file(inputs.day_path(day.year, day.day))
+            .map(Arc::from)
+            .map_err(|e| e.to_string());
+
+        let parts = (1..=day.parts).filter(|&part| args.part.is_none_or(|p| p == part));
+        for (i, part) in parts.enumerate() {
+            let input = input.clone();
+            let timeout = args.timeout;
+            jobs.push(Box::new(move || {
+                cancel::with_timeout(timeout, || (day.run)(&input?, &[part]).map_err(|e| e.to_string()))
+            }));
+            labels.push((day.day, part, i == 0));
+        }
+    }
+
+    let threads = args.jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+
+    pool::run_all(jobs, threads, args.timeout.map(|t| t + TIMEOUT_GRACE)).into_iter()
+        .zip(labels)
+        .map(|(outcome, (day, part, first))| {
+            let (outcome, parse_time, time) = match outcome {
+                Outcome::Done(Ok(run)) => match run.parts.into_iter().next() {
+                    Some(part) => {
+                        let answer = part.answer.map_err(|e| e.to_string());
+                        (answer, first.then_some(run.parse_time), part.time)
+                    },
+                    None => (Err("no result".to_owned()), None, Duration::ZERO)
+                },
+                Outcome::Done(Err(e)) => (Err(e), None, Duration::ZERO),
+                Outcome::Panicked(message) => (Err(format!("panicked: {message}")), None, Duration::ZERO),
+                Outcome::TimedOut => {
+                    let timeout = args.timeout.unwrap_or_default();
+                    (Err(AocError::TimedOut(timeout).to_string()), None, timeout)
+                }
+            };
+
+            Row { day, part, outcome, parse_time, time }
+        })
+        .collect()
+}
+
+fn run(args: RunArgs, format: Format) -> bool {
+    let mut ok = true;
+    let mut records: Vec<Record> = Vec::new();
+
+    for (i, inputs) in args.inputs.iter().enumerate() {
+        let rows = solve(&args, inputs);
+        ok &= rows.iter().all(|row| row.outcome.is_ok());
+
+        if format == Format::Table {
+            if let Some(profile) = &inputs.profile {
+                println!("{}{profile}:", if i > 0 { "\n" } else { "" });
+            }
+            print_table(&rows);
+            continue;
+        }
+
+        let profile = inputs.profile.as_ref().map_or(Field::Null, |p| Field::Text(p.clone()));
+        records.extend(rows.iter().map(|row| {
+            let (status, answer, error) = match &row.outcome {
+                Ok(answer) => ("ok", Field::Text(answer.to_string()), Field::Null),
+                Err(e) => ("error", Field::Null, Field::Text(e.clone()))
+            };
+            vec![
+                ("profile", profile.clone()),
+                ("year", Field::Number(args.year.into())),
+                ("day", Field::Number(row.day.into())),
+                ("part", Field::Number(row.part as u128)),
+                ("status", Field::Text(status.to_owned())),
+                ("answer", answer),
+                ("error", error),
+                ("parse_ns", row.parse_time.map_or(Field::Null, Field::from)),
+                ("solve_ns", row.time.into())
+            ]
+        }));
+    }
+
+    if format != Format::Table {
+        print_records(format, &records);
+    }
+    ok
+}
+
+fn verify(args: RunArgs) -> Result<bool, String> {
+    let mut ok = true;
+    for (i, inputs) in args.inputs.iter().enumerate() {
+        if let Some(profile) = &inputs.profile {
+            println!("{}{profile}:", if i > 0 { "\n" } else { "" });
+        }
+        ok &= verify_inputs(&args, inputs)?;
+    }
+    Ok(ok)
+}
+
+/// Checks the answers for `inputs` against those recorded alongside them.
+fn verify_inputs(args: &RunArgs, inputs: &Inputs) -> Result<bool, String> {
+    let path = inputs.answers_path(args.year);
+    let expected = Answers::load(&path)?;
+    let rows = solve(args, inputs);
+
+    let (mut passed, mut mismatched, mut failed, mut unknown) = (0, 0, 0, 0);
+    println!("{:>3}  {:>4}  {:<8}  Answer", "Day", "Part", "Status");
+    for row in &rows {
+        let (status, detail) = match (&row.outcome, expected.get(row.day, row.part)) {
+            (Err(e), _) => {
+                failed += 1;
+                ("fail", e.clone())
+            },
+            (Ok(answer), None) => {
+                unknown += 1;
+                ("unknown", answer.to_string())
+            },
+            (Ok(answer), Some(want)) if answers::matches(want, answer) => {
+                passed += 1;
+                ("pass", answer.to_string())
+            },
+            (Ok(answer), Some(want)) => {
+                mismatched += 1;
+                ("mismatch", format!("{answer} (expected {want})"))
+            }
+        };
+        println!("{:>3}  {:>4}  {:<8}  {}", row.day, row.part, status, detail);
+    }
+
+    println!(
+        "\n{passed} passed, {mismatched} mismatched, {failed} failed, {unknown} without a recorded answer in {}",
+        path.display()
+    );
+    Ok(mismatched == 0 && failed == 0)
+}
+
+fn fetch(args: RunArgs) -> Result<bool, String> {
+    if args.part.is_some() || args.jobs.is_some() || args.timeout.is_some() {
+        return Err("fetch takes only days, --inputs and --profile".to_owned());
+    }
+    let [inputs] = &args.inputs[..] else {
+        return Err("fetch downloads to one profile at a time".to_owned());
+    };
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return Ok(false);
+        }
+    };
+    let mut ok = true;
+    for day in &args.days {
+        let path = inputs.day_path(day.year, day.day);
+        match fetch::fetch_input(&client, day.year, day.day, &path) {
+            Ok(true) => println!("day {}: fetched to {}", day.day, path.display()),
+            Ok(false) => println!("day {}: already in {}", day.day, path.display()),
+            Err(e) => {
+                eprintln!("day {}: {e}", day.day);
+                ok = false;
+            }
+        }
+    }
+    Ok(ok)
+}
+
+/// Parses `<day> <part>`, then the options `run` takes that make sense for a single answer.
+fn parse_submit_args(args: &[String]) -> Result<RunArgs, String> {
+    let [day, part, rest @ ..] = args else {
+        return Err("submit needs a day and a part".to_owned());
+    };
+    let part = parse_part(part)?;
+
+    let mut run = parse_run_args(&[std::slice::from_ref(day), rest].concat(), None)?;
+    if run.part.is_some() || run.jobs.is_some() {
+        return Err("submit takes a day and a part, --timeout, --inputs and --profile".to_owned());
+    }
+    if run.days.len() != 1 || run.inputs.len() != 1 {
+        return Err("submit answers one day, for one profile, at a time".to_owned());
+    }
+
+    run.part = Some(part);
+    Ok(run)
+}
+
+fn submit(args: RunArgs) -> Result<bool, String> {
+    let inputs = &args.inputs[0];
+    let (year, day, part) = (args.year, args.days[0].day, args.part.unwrap_or(1));
+    let path = inputs.answers_path(year);
+    let mut answers = Answers::load(&path)?;
+
+    let row = solve(&args, inputs).remove(0);
+    let answer = row.outcome.map_err(|e| format!("day {day} part {part}: {e}"))?;
+
+    if let Some(known) = answers.get(day, part) {
+        let agrees = answers::matches(known, &answer);
+        let how = if agrees { "matches" } else { "differs from" };
+        println!("day {day} part {part}: {answer} {how} the recorded answer {known}, not submitting");
+        return Ok(agrees);
+    }
+    if let Some(rejection) = answers.rejected(day, part, &answer) {
+        println!("day {day} part {part}: {answer} is known to be {rejection}, not submitting");
+        return Ok(false);
+    }
+
+    let client = Client::from_env().map_err(|e| e.to_string())?;
+    let verdict = client.submit(year, day, part, &answer).map_err(|e| e.to_string())?;
+    println!("day {day} part {part}: {answer}: {verdict}");
+
+    match verdict {
+        Verdict::Correct => answers.set(day, part, answer),
+        Verdict::Rejected(rejection) => answers.reject(day, part, answer, rejection),
+        Verdict::Wait(_) | Verdict::WrongLevel => return Ok(false)
+    }
+    answers.save(&path)?;
+    Ok(verdict == Verdict::Correct)
+}
+
+/// Parses `<day> [--year <year>]` for a day that needn't be solved yet, so isn't in the registry.
+fn parse_new_args(args: &[String]) -> Result<(u16, u8), String> {
+    let (year, day) = match args {
+        [day] => (days::years().last().ok_or("no year given")?, day),
+        [day, flag, year] | [flag, year, day] if flag == "--year" || flag == "-y" => {
+            (year.parse().map_err(|_| format!("invalid year: {year}"))?, day)
+        },
+        _ => return Err("new takes a day and optionally --year".to_owned())
+    };
+
+    match day.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok((year, day)),
+        _ => Err(format!("invalid day: {day}"))
+    }
+}
+
+/// One line of benchmark output: a part, or the parse step if `part` is `None`.
+struct BenchRow {
+    day: u8,
+    part: Option<usize>,
+    stats: Stats
+}
+
+fn bench(args: BenchArgs) -> bool {
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+
+    let inputs = &args.run.inputs[0];
+    for &day in &args.run.days {
+        let parts: Vec<usize> = (1..=day.parts)
+            .filter(|&part| args.run.part.is_none_or(|p| p == part))
+            .collect();
+
+        let result = read_file(inputs.day_path(day.year, day.day))
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                bench::bench(day, &input, &parts, args.warmup, args.runs).map_err(|e| e.to_string())
+            });
+
+        match result {
+            Ok(Bench { parse, parts }) => {
+                if args.parse {
+                    rows.push(BenchRow { day: day.day, part: None, stats: parse });
+                }
+                for (part, stats) in parts {
+                    rows.push(BenchRow { day: day.day, part: Some(part), stats });
+                }
+            },
+            Err(e) => errors.push(format!("day {}: {}", day.day, e))
+        }
+    }
+
+    if args.format == Format::Table {
+        print_bench_table(&rows);
+    }
+    else {
+        let records: Vec<Record> = rows.iter()
+            .map(|row| {
+                let Stats { runs, min, median, mean, stddev } = row.stats;
+                vec![
+                    ("year", Field::Number(args.run.year.into())),
+                    ("day", Field::Number(row.day.into())),
+                    ("part", Field::Text(bench_part_label(row))),
+                    ("runs", Field::Number(runs as u128)),
+                    ("min_ns", min.into()),
+                    ("median_ns", median.into()),
+                    ("mean_ns", mean.into()),
+                    ("stddev_ns", stddev.into())
+                ]
+            })
+            .collect();
+        print_records(args.format, &records);
+    }
+
+    for e in &errors {
+        eprintln!("{e}");
+    }
+    errors.is_empty()
+}
+
+fn bench_part_label(row: &BenchRow) -> String {
+    row.part.map_or("parse".to_owned(), |part| part.to_string())
+}
+
+fn print_bench_table(rows: &[BenchRow]) {
+    println!(
+        "{:>3}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>5}",
+        "Day", "Part", "Min", "Median", "Mean", "Stddev", "Runs"
+    );
+    for row in rows {
+        let Stats { runs, min, median, mean, stddev } = row.stats;
+        println!(
+            "{:>3}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>5}",
+            row.day,
+            bench_part_label(row),
+            format_duration(min),
+            format_duration(median),
+            format_duration(mean),
+            format_duration(stddev),
+            runs
+        );
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let answers: Vec<String> = rows.iter()
+        .map(|row| row.outcome.as_ref().map_or("error".to_owned(), |a| a.to_string()))
+        .collect();
+
+    let answer_width = answers.iter()
+        .map(|a| a.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>10}  {:>10}",
+        "Day", "Part", "Answer", "Parse", "Solve"
+    );
+    for (row, answer) in rows.iter().zip(answers) {
+        let parse_time = row.parse_time.map(format_duration).unwrap_or_default();
+        println!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>10}  {:>10}",
+            row.day, row.part, answer, parse_time, format_duration(row.time)
+        );
+    }
+
+    let total: Duration = rows.iter()
+        .map(|row| row.parse_time.unwrap_or_default() + row.time)
+        .sum();
+    let failed = rows.iter().filter(|row| row.outcome.is_err()).count();
+    println!("\n{} parts in {} ({} failed)", rows.len(), format_duration(total), failed);
+
+    for row in rows {
+        if let Err(e) = &row.outcome {
+            eprintln!("day {} part {}: {}", row.day, row.part, e);
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => take_format(&args[1..]).and_then(|(args, format)| {
+            parse_run_args(&args, None).map(|args| run(args, format))
+        }),
+        Some("bench") => parse_bench_args(&args[1..]).map(bench),
+        Some("verify") => parse_run_args(&args[1..], Some("all")).map(|args| {
+            verify(args).unwrap_or_else(|e| {
+                eprintln!("error: {e}");
+                false
+            })
+        }),
+        Some("fetch") => parse_run_args(&args[1..], None).and_then(fetch),
+        Some("submit") => parse_submit_args(&args[1..]).map(|args| {
+            submit(args).unwrap_or_else(|e| {
+                eprintln!("error: {e}");
+                false
+            })
+        }),
+        Some("new") => parse_new_args(&args[1..])
+            .map(|(year, day)| new::new_day(year, day).map_err(|e| eprintln!("error: {e}")).is_ok()),
+        Some("help" | "--help" | "-h") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        },
+        Some(other) => Err(format!("unknown command: {other}")),
+        None => Err("no command given".to_owned())
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use adventofcode::{input_root, Inputs};
+
+const DAY_TEMPLATE: &str = include_str!("templates/day.rs.template");
+const BIN_TEMPLATE: &str = include_str!("templates/bin.rs.template");
+const YEAR_TEMPLATE: &str = include_str!("templates/year.rs.template");
+
+/// Fills in `{{year}}`, `{{day}}` and the zero-padded `{{dd}}` in a template.
+fn render(template: &str, year: u16, day: u8) -> String {
+    template.replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{dd}}", &format!("{day:02}"))
+}
+
+/// Inserts `line` among the lines for which `key` gives a number, keeping them in order of it.
+fn insert_sorted(text: &str, line: &str, n: u16, key: impl Fn(&str) -> Option<u16>) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, u16)> = lines.iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l).map(|k| (i, k)))
+        .collect();
+
+    let Some(&(last, _)) = keyed.last() else {
+        return Err(format!("nowhere to add `{}`", line.trim()));
+    };
+    if keyed.iter().any(|&(_, k)| k == n) {
+        return Err(format!("`{}` is already there", line.trim()));
+    }
+    let at = keyed.iter().find(|&&(_, k)| k > n).map_or(last + 1, |&(i, _)| i);
+
+    let mut result: Vec<&str> = lines[..at].to_vec();
+    result.push(line);
+    result.extend(&lines[at..]);
+    Ok(result.join("\n") + "\n")
+}
+
+/// Adds the `mod` declaration and the `DAYS` entry for a new day to the text of a year's module.
+fn register_day(year_mod: &str, year: u16, day: u8) -> Result<String, String> {
+    let declared = |line: &str| line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok();
+    let listed = |line: &str| line.trim().strip_prefix(&format!("day!({year}, "))?.split_once(',')?.0.parse().ok();
+
+    let text = insert_sorted(year_mod, &format!("pub mod day{day:02};"), day.into(), declared)?;
+    insert_sorted(&text, &format!("    day!({year}, {day}, day{day:02}::Day{day:02}),"), day.into(), listed)
+}
+
+/// Adds the `mod` declaration and the `YEARS` entry for a new year to the text of `days/mod.rs`.
+fn register_year(days_mod: &str, year: u16) -> Result<String, String> {
+    let declared = |line: &str| line.strip_prefix("pub mod y")?.strip_suffix(';')?.parse().ok();
+    let listed = |line: &str| line.trim().strip_prefix('y')?.strip_suffix("::DAYS,")?.parse().ok();
+
+    let text = insert_sorted(days_mod, &format!("pub mod y{year};"), year, declared)?;
+    insert_sorted(&text, &format!("    y{year}::DAYS,"), year, listed)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("could not create {}: {}", dir.display(), e))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+/// Scaffolds a new day: its solution module with a test stub, its binary, its registration with
+/// the runner (adding the year's module too, for a day from a new year) and an empty input.
+pub fn new_day(year: u16, day: u8) -> Result<(), String> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let year_dir = root.join(format!("src/days/y{year}"));
+    let module = year_dir.join(format!("day{day:02}.rs"));
+    let bin = root.join(format!("src/bin/{year}-day{day:02}.rs"));
+    let input = Inputs::root(&input_root(None)).day_path(year, day);
+
+    // Work out every change before writing anything, so a failure doesn't leave a half-made day.
+    let mut files = Vec::new();
+    for path in [&module, &bin] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+    files.push((module, render(DAY_TEMPLATE, year, day)));
+    files.push((bin, render(BIN_TEMPLATE, year, day)));
+
+    let year_mod = year_dir.join("mod.rs");
+    if year_mod.exists() {
+        let registered = register_day(&read(&year_mod)?, year, day)?;
+        files.push((year_mod, registered));
+    }
+    else {
+        let days_mod = root.join("src/days/mod.rs");
+        let registered = register_year(&read(&days_mod)?, year)?;
+        files.push((year_mod, render(YEAR_TEMPLATE, year, day)));
+        files.push((days_mod, registered));
+    }
+
+    for (path, contents) in &files {
+        write(path, contents)?;
+        println!("wrote {}", path.display());
+    }
+
+    if !input.exists() {
+        write(&input, "")?;
+        println!("wrote {} (empty; paste your input in, or use `aoc fetch {day} --year {year}`)", input.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_day() {
+        let year_mod = "use super::Day;\n\npub mod day01;\npub mod day03;\n\npub static DAYS: &[Day] = &[\n    day!(2016, 1, day01::Day01),\n    day!(2016, 3, day03::Day03),\n];\n";
+
+        assert_eq!(
+            register_day(year_mod, 2016, 2).unwrap(),
+            "use super::Day;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\npub static DAYS: &[Day] = &[\n    day!(2016, 1, day01::Day01),\n    day!(2016, 2, day02::Day02),\n    day!(2016, 3, day03::Day03),\n];\n"
+        );
+        assert!(register_day(year_mod, 2016, 4).unwrap().contains("day!(2016, 3, day03::Day03),\n    day!(2016, 4, day04::Day04),\n];"));
+        assert!(register_day(year_mod, 2016, 3).is_err());
+    }
+
+    #[test]
+    fn test_register_year() {
+        let days_mod = "pub mod y2015;\n\nstatic YEARS: &[&[Day]] = &[\n    y2015::DAYS,\n];\n";
+
+        assert_eq!(
+            register_year(days_mod, 2016).unwrap(),
+            "pub mod y2015;\npub mod y2016;\n\nstatic YEARS: &[&[Day]] = &[\n    y2015::DAYS,\n    y2016::DAYS,\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(render(BIN_TEMPLATE, 2016, 7).lines().last(), Some("build_main!(2016, 7, Day07);"));
+    }
+}
@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format { Table, Json, Csv, Tsv }
+
+/// Removes `--format <name>` from `args`, returning the rest and the format (`Table` if absent).
+pub fn take_format(args: &[String]) -> Result<(Vec<String>, Format), String> {
+    let mut rest = Vec::new();
+    let mut format = Format::Table;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if arg != "--format" {
+            rest.push(arg.clone());
+            continue;
+        }
+
+        format = match args.next().map(String::as_str) {
+            Some("table") => Format::Table,
+            Some("json") => Format::Json,
+            Some("csv") => Format::Csv,
+            Some("tsv") => Format::Tsv,
+            Some(other) => return Err(format!("invalid format: {other}")),
+            None => return Err("--format needs a value".to_owned())
+        };
+    }
+
+    Ok((rest, format))
+}
+
+#[derive(Clone)]
+pub enum Field {
+    Number(u128),
+    Text(String),
+    Null
+}
+
+impl From<Duration> for Field {
+    fn from(d: Duration) -> Self {
+        Field::Number(d.as_nanos())
+    }
+}
+
+/// One result, as named fields in a fixed order.
+pub type Record = Vec<(&'static str, Field)>;
+
+/// Prints `records` as JSON (one object per line), CSV or TSV with a header line.  Tables are
+/// specific to each command, so aren't handled here.
+pub fn print_records(format: Format, records: &[Record]) {
+    match format {
+        Format::Json => {
+            for record in records {
+                let fields: Vec<String> = record.iter()
+                    .map(|(name, field)| format!("\"{name}\": {}", json(field)))
+                    .collect();
+                println!("{{{}}}", fields.join(", "));
+            }
+        },
+        Format::Csv | Format::Tsv => {
+            let (separator, escape): (&str, fn(&str) -> String) = match format {
+                Format::Csv => (",", csv),
+                _ => ("\t", tsv)
+            };
+
+            if let Some(first) = records.first() {
+                let names: Vec<&str> = first.iter().map(|&(name, _)| name).collect();
+                println!("{}", names.join(separator));
+            }
+            for record in records {
+                let fields: Vec<String> = record.iter()
+                    .map(|(_, field)| match field {
+                        Field::Number(n) => n.to_string(),
+                        Field::Text(s) => escape(s),
+                        Field::Null => String::new()
+                    })
+                    .collect();
+                println!("{}", fields.join(separator));
+            }
+        },
+        Format::Table => unreachable!("tables are printed by each command")
+    }
+}
+
+fn json(field: &Field) -> String {
+    match field {
+        Field::Number(n) => n.to_string(),
+        Field::Null => "null".to_owned(),
+        Field::Text(s) => {
+            let mut out = String::from("\"");
+            for c in s.chars() {
+                match c {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    '\n' => out.push_str("\\n"),
+                    '\t' => out.push_str("\\t"),
+                    c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+                    c => out.push(c)
+                }
+            }
+            out.push('"');
+            out
+        }
+    }
+}
+
+fn csv(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    }
+    else {
+        s.to_owned()
+    }
+}
+
+fn tsv(s: &str) -> String {
+    s.replace(['\t', '\n'], " ")
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros == 0 {
+        format!("{}ns", d.as_nanos())
+    }
+    else if micros < 1_000 {
+        format!("{micros}μs")
+    }
+    else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1e3)
+    }
+    else {
+        format!("{:.2}s", micros as f64 / 1e6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(json(&Field::Text("say \"hi\"\n".to_owned())), r#""say \"hi\"\n""#);
+        assert_eq!(csv("a, \"b\""), r#""a, ""b""""#);
+        assert_eq!(tsv("a\tb"), "a b");
+    }
+}
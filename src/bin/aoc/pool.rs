@@ -0,0 +1,139 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Some solvers recurse deeply or keep large arrays on the stack, so give workers as much room as
+/// the main thread usually gets.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+pub type Job<T> = Box<dyn FnOnce() -> T + Send>;
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome<T> {
+    Done(T),
+    /// The job panicked, with this message.
+    Panicked(String),
+    TimedOut
+}
+
+thread_local! {
+    /// Whether this thread is a worker, whose panics are reported as outcomes rather than printed.
+    static WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook so that it stays quiet on worker threads.  Other threads panic as usual.
+fn quieten_workers() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !WORKER.get() {
+                default(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => payload.downcast_ref::<String>().cloned().unwrap_or_else(|| "unknown cause".to_owned())
+    }
+}
+
+/// Runs `jobs` with at most `threads` of them at once, returning their outcomes in the order the
+/// jobs were given.  A job still running `timeout` after it started is given up on, freeing its
+/// slot for the next job; its thread is left to finish (or not) in the background.
+pub fn run_all<T: Send + 'static>(jobs: Vec<Job<T>>, threads: usize, timeout: Option<Duration>) -> Vec<Outcome<T>> {
+    quieten_workers();
+    let (tx, rx) = mpsc::channel();
+    let mut results: Vec<Outcome<T>> = jobs.iter().map(|_| Outcome::TimedOut).collect();
+    let mut jobs = jobs.into_iter().enumerate();
+    let mut running: HashMap<usize, Option<Instant>> = HashMap::new();
+
+    loop {
+        while running.len() < threads.max(1) {
+            let Some((i, job)) = jobs.next() else { break };
+            let tx = tx.clone();
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn(move || {
+                    WORKER.set(true);
+                    let outcome = match panic::catch_unwind(AssertUnwindSafe(job)) {
+                        Ok(result) => Outcome::Done(result),
+                        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref()))
+                    };
+                    // Sending fails only once the pool has returned, when nobody wants the result.
+                    let _ = tx.send((i, outcome));
+                })
+                .expect("failed to spawn a worker thread");
+
+            running.insert(i, timeout.map(|t| Instant::now() + t));
+        }
+
+        if running.is_empty() {
+            return results;
+        }
+
+        // `tx` is still held here, so receiving can't fail by disconnection.
+        let received = match running.values().flatten().min() {
+            Some(&deadline) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        };
+
+        match received {
+            Ok((i, outcome)) => {
+                if running.remove(&i).is_some() {
+                    results[i] = outcome;
+                }
+            },
+            Err(_) => {
+                let now = Instant::now();
+                running.retain(|_, deadline| deadline.is_none_or(|d| d > now));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordered_results() {
+        let jobs: Vec<Job<u64>> = (0..8)
+            .map(|i| -> Job<u64> {
+                Box::new(move || {
+                    thread::sleep(Duration::from_millis(8 - i));
+                    i
+                })
+            })
+            .collect();
+
+        let results = run_all(jobs, 3, None);
+        assert_eq!(results, (0..8).map(Outcome::Done).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let jobs: Vec<Job<u32>> = vec![
+            Box::new(|| 1),
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(5));
+                2
+            }),
+            Box::new(|| panic!("job {} panicked", 3)),
+            Box::new(|| 4)
+        ];
+
+        let start = Instant::now();
+        let results = run_all(jobs, 1, Some(Duration::from_millis(100)));
+        assert_eq!(results, [Outcome::Done(1), Outcome::TimedOut, Outcome::Panicked("job 3 panicked".to_owned()), Outcome::Done(4)]);
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,57 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+use crate::error::AocError;
+
+// Solvers can't be interrupted from outside, so instead the runner gives the thread solving a part
+// a deadline and long-running loops check it from time to time, giving up with
+// `AocError::TimedOut` once it has passed.
+
+thread_local! {
+    /// When the current thread's work must stop, and the limit that was given.
+    static DEADLINE: Cell<Option<(Instant, Duration)>> = const { Cell::new(None) };
+}
+
+/// How many iterations of a hot loop pass between looks at the clock in [`tick`].
+const TICK_INTERVAL: usize = 1 << 12;
+
+/// Runs `f` with a deadline `limit` from now, or none, restoring the previous deadline afterwards.
+pub fn with_timeout<R>(limit: Option<Duration>, f: impl FnOnce() -> R) -> R {
+    let previous = DEADLINE.replace(limit.map(|limit| (Instant::now() + limit, limit)));
+    let result = f();
+    DEADLINE.set(previous);
+    result
+}
+
+/// Fails once the current thread's deadline has passed.
+pub fn check() -> Result<(), AocError> {
+    match DEADLINE.get() {
+        Some((deadline, limit)) if Instant::now() >= deadline => Err(AocError::TimedOut(limit)),
+        _ => Ok(())
+    }
+}
+
+/// [`check`] for loops too tight to read the clock every time round: checks only on every
+/// `TICK_INTERVAL`th value of the loop counter `i`.
+pub fn tick(i: usize) -> Result<(), AocError> {
+    if i.is_multiple_of(TICK_INTERVAL) { check() } else { Ok(()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeout() {
+        assert_eq!(check(), Ok(()));
+
+        let limit = Duration::from_millis(10);
+        let result = with_timeout(Some(limit), || {
+            assert_eq!(check(), Ok(()));
+            std::thread::sleep(limit);
+            (tick(1), tick(0))
+        });
+        assert_eq!(result, (Ok(()), Err(AocError::TimedOut(limit))));
+
+        assert_eq!(check(), Ok(()));
+    }
+}
@@ -0,0 +1,42 @@
+use crate::error::AocError;
+use crate::solution::Run;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub parts: usize,
+    /// Parses the input and solves the given parts; see [`run`](crate::solution::run).
+    pub run: fn(&str, &[usize]) -> Result<Run, AocError>
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:ty) => {
+        $crate::days::Day {
+            year: $year,
+            day: $day,
+            parts: <$solution as $crate::solution::Solution>::PARTS,
+            run: $crate::solution::run::<$solution>
+        }
+    };
+}
+
+pub mod y2015;
+
+/// The solved days of each year, in order of year.
+static YEARS: &[&[Day]] = &[
+    y2015::DAYS,
+];
+
+/// Every solved day, in order.
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+/// The years with at least one solved day, in order.
+pub fn years() -> impl Iterator<Item = u16> {
+    YEARS.iter().filter_map(|days| days.first()).map(|day| day.year)
+}
+
+pub fn get(year: u16, day: u8) -> Option<&'static Day> {
+    all().find(|d| d.year == year && d.day == day)
+}
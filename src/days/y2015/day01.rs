@@ -0,0 +1,53 @@
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let input = input.trim_end();
+        input.char_indices()
+            .map(|(i, c)| {
+                match c {
+                    '(' => Ok(1),
+                    ')' => Ok(-1),
+                    _ => Err(AocError::parse_at(input, i, format!("bad character {c:?}")))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(input.iter().sum::<isize>().into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut cur = 0;
+        for (i, step) in input.iter().enumerate() {
+            cur += step;
+            if cur < 0 {
+                return Ok((i + 1).into());
+            }
+        }
+
+        Err(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day01;
+        balanced: "(())" => part1 = 0;
+        alternating: "()()" => part1 = 0;
+        all_up: "(((" => part1 = 3;
+        up_overall: "))(((((" => part1 = 3;
+        down_overall: ")())())" => part1 = -3;
+        basement_first: ")" => part1 = -1, part2 = 1;
+        basement_last: "()())" => part1 = -1, part2 = 5;
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+mod parse {
+    use nom::character::complete::char;
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::sequence::{preceded, tuple};
+    use crate::parse::{lines, unsigned, IResult};
+
+    pub fn boxes(input: &str) -> IResult<&str, Vec<[usize; 3]>> {
+        lines(
+            context(
+                "box dimensions",
+                map(
+                    tuple((
+                        unsigned,
+                        preceded(char('x'), unsigned),
+                        preceded(char('x'), unsigned)
+                    )),
+                    |(l, w, h)| [l, w, h]
+                )
+            )
+        )(input)
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<[usize; 3]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse::boxes(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let result = input.iter()
+            .map(|&arr| {
+                let mut arr = arr;
+                arr.sort();
+                let [l, w, h] = arr;
+                2*l*w + 2*w*h + 2*h*l + l * w
+            })
+            .sum::<usize>();
+
+        Ok(result.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let result = input.iter()
+            .map(|&arr| {
+                let mut arr = arr;
+                arr.sort();
+                let [l, w, h] = arr;
+                2 * l + 2 * w + l * w * h
+            })
+            .sum::<usize>();
+
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day02;
+        cuboid: "2x3x4" => part1 = 58, part2 = 34;
+        long_box: "1x1x10" => part1 = 43, part2 = 14;
+        both: "2x3x4\n1x1x10\n" => part1 = 101, part2 = 48;
+    }
+}
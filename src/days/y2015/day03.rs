@@ -0,0 +1,112 @@
+use nom::branch::alt;
+use nom::character::complete::char;
+use nom::combinator::value;
+use nom::error::context;
+use nom::multi::many1;
+use crate::error::AocError;
+use crate::grid::{Pos, SparseGrid};
+use crate::parse::{finish, IResult};
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone)]
+pub enum Move { Up, Left, Down, Right }
+use Move::*;
+
+impl Move {
+    fn apply(&self, (i, j): Pos) -> Pos {
+        match self {
+            Up => (i - 1, j),
+            Down => (i + 1, j),
+            Left => (i, j - 1),
+            Right => (i, j + 1)
+        }
+    }
+}
+
+struct State {
+    santa: Pos,
+    robot: Pos,
+    /// Presents delivered to each house visited.
+    presents: SparseGrid<usize>
+}
+
+impl State {
+    fn new() -> State {
+        let santa = (0, 0);
+        let robot = (0, 0);
+        let mut presents = SparseGrid::new();
+        presents.insert(santa, 1);
+        State { santa, robot, presents }
+    }
+
+    fn deliver(&mut self, house: Pos) {
+        *self.presents.entry(house).or_insert(0) += 1;
+    }
+
+    fn move_santa(&mut self, mv: Move) {
+        self.santa = mv.apply(self.santa);
+        self.deliver(self.santa);
+    }
+
+    fn move_robot(&mut self, mv: Move) {
+        self.robot = mv.apply(self.robot);
+        self.deliver(self.robot);
+    }
+}
+
+fn parse_moves(input: &str) -> IResult<&str, Vec<Move>> {
+    many1(
+        context(
+            "move",
+            alt((
+                value(Down, char('v')),
+                value(Up, char('^')),
+                value(Left, char('<')),
+                value(Right, char('>'))
+            ))
+        )
+    )(input)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse_moves(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let state = input.iter()
+            .fold(State::new(), |mut acc, &cur| {
+                acc.move_santa(cur);
+                acc
+            });
+
+        Ok(state.presents.len().into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let state = input.iter().enumerate()
+            .fold(State::new(), |mut acc, (i, &cur)| {
+                if i % 2 == 0 { acc.move_santa(cur) } else { acc.move_robot(cur) };
+                acc
+            });
+
+        Ok(state.presents.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day03;
+        one_move: ">" => part1 = 2;
+        square: "^>v<" => part1 = 4, part2 = 3;
+        back_and_forth: "^v^v^v^v^v" => part1 = 2, part2 = 11;
+        split_up: "^v" => part2 = 3;
+    }
+}
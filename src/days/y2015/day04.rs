@@ -0,0 +1,49 @@
+use crate::cancel;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+fn first_with_zeros(key: &str, zeros: usize) -> Result<usize, AocError> {
+    let mut i = 0;
+
+    loop {
+        cancel::tick(i)?;
+        let s = format!("{key}{i}");
+        let d = md5::compute(&s);
+
+        if format!("{d:x}").chars().take(zeros).all(|ch| ch == '0') {
+            return Ok(i)
+        }
+
+        i += 1;
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input.trim())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(first_with_zeros(input, 5)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(first_with_zeros(input, 6)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day04;
+        abcdef: "abcdef" => part1 = 609043;
+        #[ignore = "slow in debug builds"]
+        pqrstuv: "pqrstuv" => part1 = 1048970;
+    }
+}
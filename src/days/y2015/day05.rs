@@ -0,0 +1,142 @@
+use std::ops::{Index, IndexMut};
+use itertools::Itertools;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+fn is_nice1(input: &str) -> bool {
+    let mut vowels = 0;
+    let mut dups = 0;
+
+    if input.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        vowels += 1;
+    }
+
+    for (prev, cur) in input.chars().tuple_windows() {
+        if ['a', 'e', 'i', 'o', 'u'].contains(&cur) {
+            vowels += 1;
+        }
+
+        if prev == cur {
+            dups += 1;
+        }
+
+        if [('a', 'b'), ('c', 'd'), ('p', 'q'), ('x', 'y')].contains(&(prev, cur)) {
+            return false;
+        }
+    }
+
+    vowels >= 3 && dups >= 1
+}
+
+#[derive(Copy, Clone)]
+struct CharMap<T> {
+    data: [T; 26]
+}
+
+impl<T> Index<char> for CharMap<T> {
+    type Output = T;
+
+    fn index(&self, index: char) -> &Self::Output {
+        &self.data[(index as usize) - ('a' as usize)]
+    }
+}
+
+impl<T> IndexMut<char> for CharMap<T> {
+    fn index_mut(&mut self, index: char) -> &mut Self::Output {
+        &mut self.data[(index as usize) - ('a' as usize)]
+    }
+}
+
+impl<T: Default + Copy> Default for CharMap<T> {
+    fn default() -> Self {
+        let data = [Default::default(); 26];
+        Self { data }
+    }
+}
+
+fn is_nice2(input: &str) -> bool {
+    let contains_triple = input.chars()
+        .tuple_windows()
+        .any(|(a, _, c)| a == c);
+
+    if !contains_triple {
+        return false
+    }
+
+    let mut first_seen: CharMap<CharMap<Option<usize>>> = Default::default();
+
+    for (i, (a, b)) in input.chars().tuple_windows().enumerate() {
+        match first_seen[a][b] {
+            Some(last) => {
+                if i > last + 1 {
+                    return true
+                }
+            },
+            None => {
+                first_seen[a][b] = Some(i);
+            }
+        }
+    }
+
+    false
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        // The strings index a `CharMap`, so anything but lowercase letters would be out of bounds.
+        // Line endings may be `\n` or `\r\n`.
+        let bad = input.char_indices().find(|&(i, c)| {
+            !c.is_ascii_lowercase() && c != '\n' && !(c == '\r' && input[i + 1..].starts_with('\n'))
+        });
+        if let Some((i, _)) = bad {
+            return Err(AocError::parse_at(input, i, "expected a lowercase letter"));
+        }
+
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let count = input.iter()
+            .filter(|&line| is_nice1(line))
+            .count();
+
+        Ok(count.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let count = input.iter()
+            .filter(|&line| is_nice2(line))
+            .count();
+
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day05;
+        nice: "ugknbfddgicrmopn" => part1 = 1;
+        overlapping: "aaa" => part1 = 1;
+        no_double: "jchzalrnumimnmhp" => part1 = 0;
+        forbidden: "haegwjzuvuyypxyu" => part1 = 0;
+        one_vowel: "dvszwmarrgswjxmb" => part1 = 0;
+        nice_new_rules: "qjhvhtzxzqqjkmpb" => part2 = 1;
+        overlapping_letters: "xxyxx" => part2 = 1;
+        no_repeat: "uurcxstgmygtbstg" => part2 = 0;
+        no_pair: "ieodomkazucvgmuy" => part2 = 0;
+        crlf: "ugknbfddgicrmopn\r\naaa\r\n" => part1 = 2;
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = AocError::Parse { line: 2, column: 4, message: "expected a lowercase letter".to_owned() };
+        assert_eq!(Day05::parse("aaa\r\naaa\rbbb").err(), Some(error));
+    }
+}
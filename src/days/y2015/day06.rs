@@ -0,0 +1,168 @@
+use std::cmp::{max, min};
+use itertools::Itertools;
+use crate::error::AocError;
+use crate::grid::{Grid, Pos};
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone)]
+pub enum Operation { TurnOff, Toggle, TurnOn }
+use Operation::*;
+
+/// The lights are in a square this many to a side.
+const SIZE: usize = 1000;
+
+pub struct Instruction {
+    operation: Operation,
+    from: Pos,
+    to: Pos
+}
+
+impl Instruction {
+    /// The lights in the rectangle the instruction applies to.
+    fn lights(&self) -> impl Iterator<Item = Pos> {
+        let rows = min(self.from.0, self.to.0)..=max(self.from.0, self.to.0);
+        let cols = min(self.from.1, self.to.1)..=max(self.from.1, self.to.1);
+        rows.cartesian_product(cols)
+    }
+}
+
+mod parse {
+    use nom::branch::alt;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::{char, space1};
+    use nom::combinator::{map, value};
+    use nom::error::context;
+    use nom::sequence::{preceded, separated_pair, tuple};
+    use crate::parse::{lines, unsigned, IResult};
+    use super::{Instruction, Operation, Pos};
+    use super::Operation::{Toggle, TurnOff, TurnOn};
+
+    fn operation(input: &str) -> IResult<&str, Operation> {
+        context(
+            "operation",
+            alt((
+                value(TurnOff, tag("turn off")),
+                value(TurnOn, tag("turn on")),
+                value(Toggle, tag("toggle"))
+            ))
+        )(input)
+    }
+
+    fn pos(input: &str) -> IResult<&str, Pos> {
+        context(
+            "position",
+            separated_pair(
+                unsigned,
+                char(','),
+                unsigned
+            )
+        )(input)
+    }
+
+    fn instruction(input: &str) -> IResult<&str, Instruction> {
+        context(
+            "instruction",
+            map(
+                tuple((
+                    operation,
+                    preceded(space1, pos),
+                    preceded(tag(" through "), pos)
+                )),
+                |(op, from, to)| Instruction { operation: op, from, to}
+            )
+        )(input)
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Instruction>> {
+        lines(instruction)(input)
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let instructions = finish(input, parse::input(input))?;
+
+        let out_of_bounds = instructions.iter()
+            .flat_map(|instr| [instr.from, instr.to])
+            .find(|&(i, j)| i >= SIZE as isize || j >= SIZE as isize);
+
+        match out_of_bounds {
+            Some((i, j)) => Err(AocError::invalid_input(format!("light {i},{j} is outside the grid"))),
+            None => Ok(instructions)
+        }
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut total: usize = 0;
+        let mut state = Grid::new(SIZE, SIZE, false);
+
+        for instr in input {
+            for p in instr.lights() {
+                match (instr.operation, state[p]) {
+                    (TurnOn, false) => {
+                        state[p] = true;
+                        total += 1;
+                    },
+                    (TurnOff, true) => {
+                        state[p] = false;
+                        total -= 1;
+                    }
+                    (Toggle, true) => {
+                        state[p] = false;
+                        total -= 1;
+                    },
+                    (Toggle, false) => {
+                        state[p] = true;
+                        total += 1;
+                    },
+                    _ => { continue }
+                }
+            }
+        }
+
+        Ok(total.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut state = Grid::new(SIZE, SIZE, 0);
+
+        for instr in input {
+            for p in instr.lights() {
+                match (instr.operation, state[p]) {
+                    (TurnOn, _) => {
+                        state[p] += 1;
+
+                    },
+                    (TurnOff, b) if b > 0 => {
+                        state[p] -= 1;
+                    }
+                    (Toggle, _) => {
+                        state[p] += 2;
+                    },
+                    _ => { continue }
+                }
+            }
+        }
+
+        Ok(state.values().sum::<usize>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day06;
+        everything_on: "turn on 0,0 through 999,999" => part1 = 1_000_000, part2 = 1_000_000;
+        toggle_first_row: "toggle 0,0 through 999,0" => part1 = 1000, part2 = 2000;
+        off_in_the_middle: "turn on 0,0 through 999,999\nturn off 499,499 through 500,500" => part1 = 999_996;
+        one_brighter: "turn on 0,0 through 0,0" => part2 = 1;
+        all_toggled: "toggle 0,0 through 999,999" => part2 = 2_000_000;
+    }
+}
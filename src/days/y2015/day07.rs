@@ -0,0 +1,183 @@
+use std::collections::{HashMap, HashSet};
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone, Debug)]
+pub enum InputSpec<'a> {
+    Literal(u16),
+    Wire(&'a str)
+}
+use InputSpec::*;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Gate<'a> {
+    Direct(InputSpec<'a>),
+    Not(InputSpec<'a>),
+    And(InputSpec<'a>, InputSpec<'a>),
+    Or(InputSpec<'a>, InputSpec<'a>),
+    LShift(InputSpec<'a>, InputSpec<'a>),
+    RShift(InputSpec<'a>, InputSpec<'a>)
+}
+use Gate::*;
+
+#[derive(Clone)]
+pub struct Diagram<'a> {
+    wires: HashMap<&'a str, Gate<'a>>,
+    values: HashMap<&'a str, u16>,
+    /// The wires whose values are being worked out, for spotting loops.
+    evaluating: HashSet<&'a str>
+}
+
+impl<'a> Diagram<'a> {
+    fn eval(&mut self, input_spec: InputSpec<'a>) -> Result<u16, AocError> {
+        match input_spec {
+            Literal(x) => Ok(x),
+            Wire(w) => {
+                if !self.values.contains_key(w) {
+                    let gate = *self.wires.get(w)
+                        .ok_or_else(|| AocError::invalid_input(format!("nothing drives wire {w}")))?;
+                    if !self.evaluating.insert(w) {
+                        return Err(AocError::invalid_input(format!("wire {w} depends on itself")));
+                    }
+
+                    let result = match gate {
+                        Direct(u) => self.eval(u)?,
+                        Not(u) => !self.eval(u)?,
+                        And(u1, u2) => self.eval(u1)? & self.eval(u2)?,
+                        Or(u1, u2) => self.eval(u1)? | self.eval(u2)?,
+                        LShift(u1, u2) => self.eval(u1)?.checked_shl(self.eval(u2)? as u32).unwrap_or(0),
+                        RShift(u1, u2) => self.eval(u1)?.checked_shr(self.eval(u2)? as u32).unwrap_or(0),
+                    };
+                    self.evaluating.remove(w);
+                    self.values.insert(w, result);
+                }
+
+                Ok(self.values[&w])
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.evaluating.clear();
+    }
+}
+
+mod parse {
+    use super::Gate::*;
+    use super::InputSpec::{Literal, Wire};
+    use super::{Diagram, Gate, InputSpec};
+    use nom::branch::alt;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::alphanumeric1;
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::sequence::{preceded, separated_pair};
+    use crate::parse::{lines, IResult};
+    use std::collections::{HashMap, HashSet};
+
+    fn input_spec(input: &str) -> IResult<&str, InputSpec<'_>> {
+        context(
+            "wire or signal",
+            map(
+                alphanumeric1,
+                |s: &str| {
+                    match s.parse::<u16>() {
+                        Ok(n) => Literal(n),
+                        Err(_) => Wire(s)
+                    }
+                }
+            )
+        )(input)
+    }
+
+    fn gate(input: &str) -> IResult<&str, Gate<'_>> {
+        context(
+            "gate",
+            alt((
+                map(preceded(tag("NOT "), input_spec), Not),
+                map(separated_pair(input_spec, tag(" AND "), input_spec), |(a, b)| And(a, b)),
+                map(separated_pair(input_spec, tag(" OR "), input_spec), |(a, b)| Or(a, b)),
+                map(separated_pair(input_spec, tag(" LSHIFT "), input_spec), |(a, b)| LShift(a, b)),
+                map(separated_pair(input_spec, tag(" RSHIFT "), input_spec), |(a, b)| RShift(a, b)),
+                map(input_spec, Direct)
+            ))
+        )(input)
+    }
+
+    pub fn diagram(input: &str) -> IResult<&str, Diagram<'_>> {
+        map(
+            lines(
+                context(
+                    "connection",
+                    map(
+                        separated_pair(gate, tag(" -> "), context("wire", alphanumeric1)),
+                        |(a, b)| (b, a)
+                    )
+                )
+            ),
+            |v| {
+                let wires: HashMap<&str, Gate> = v.into_iter().collect();
+                let values: HashMap<&str, u16> = HashMap::new();
+                Diagram { wires, values, evaluating: HashSet::new() }
+            }
+        )(input)
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Diagram<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse::diagram(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut diagram = input.clone();
+        Ok(diagram.eval(Wire("a"))?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut diagram = input.clone();
+        let orig_a = diagram.eval(Wire("a"))?;
+
+        diagram.clear();
+        diagram.wires.insert("b", Direct(Literal(orig_a)));
+
+        Ok(diagram.eval(Wire("a"))?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example from the puzzle, with wire `d` renamed to `a` since that's the one the answer
+    // is read from.
+    const TEST_INPUT: &str = "123 -> x
+456 -> y
+x AND y -> a
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    crate::examples! {
+        Day07;
+        circuit: TEST_INPUT => part1 = 72;
+    }
+
+    #[test]
+    fn test_loop() -> Result<(), AocError> {
+        let diagram = Day07::parse("a -> a")?;
+        assert_eq!(Day07::part1(&diagram), Err(AocError::invalid_input("wire a depends on itself")));
+
+        let diagram = Day07::parse("1 -> y\nx AND y -> x\nx -> a")?;
+        assert_eq!(Day07::part1(&diagram), Err(AocError::invalid_input("wire x depends on itself")));
+        Ok(())
+    }
+}
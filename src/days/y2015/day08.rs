@@ -0,0 +1,70 @@
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+fn count_diff(input: &str) -> Result<usize, AocError> {
+    let mut result = 0;
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c == '"' {
+            result += 1;
+        }
+        else if c == '\\' {
+            let next = chars.next()
+                .ok_or_else(|| AocError::invalid_input(format!("dangling escape in {input}")))?;
+            if next == 'x' {
+                result += 3;
+                chars.next();
+                chars.next();
+            }
+            else {
+                result += 1;
+            }
+        }
+        else {
+            continue
+        }
+    }
+
+    Ok(result)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let total = input.iter()
+            .map(|line| count_diff(line))
+            .sum::<Result<usize, _>>()?;
+
+        Ok(total.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let total = input.iter()
+            .map(|line| line.chars().filter(|&c| c == '\\' || c == '"').count() + 2)
+            .sum::<usize>();
+
+        Ok(total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#;
+
+    crate::examples! {
+        Day08;
+        strings: TEST_INPUT => part1 = 12, part2 = 19;
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::search::{branch_and_bound, dijkstra, Objective, Problem};
+use crate::solution::{Answer, Solution};
+
+pub struct Graph {
+    num_nodes: usize,
+    dists: Vec<Vec<usize>>
+}
+
+impl Graph {
+    fn from_dists(dist_list: Vec<(&str, &str, usize)>) -> Graph {
+        let city_names: Vec<&str> = dist_list.iter()
+            .flat_map(|&(a, b, _)| vec![a, b])
+            .unique()
+            .collect();
+
+        let city_ids: HashMap<&str, usize> = city_names.iter().enumerate()
+            .map(|(i, &name)| (name, i))
+            .collect();
+        
+        let num_nodes = city_names.len();
+
+        let mut dists = vec![vec![0; num_nodes]; num_nodes];
+        for (a, b, dist) in dist_list.into_iter() {
+            let i_a = city_ids[&a];
+            let i_b = city_ids[&b];
+            dists[i_a][i_b] = dist;
+            dists[i_b][i_a] = dist;
+        }
+
+        Graph { num_nodes, dists }
+    }
+}
+
+/// A route partway through: the cities visited, as a bit each, and the one it's at (`None` before
+/// the start).
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Route {
+    visited: usize,
+    at: Option<usize>
+}
+
+/// The search for a route visiting every city once, each leg costing its distance.
+struct Tour<'a> {
+    graph: &'a Graph,
+    /// When searching for the longest route, the longest leg: no leg still to go can be longer.
+    longest_leg: Option<usize>
+}
+
+impl Problem for Tour<'_> {
+    type State = Route;
+    type Cost = usize;
+
+    fn start(&self) -> Route {
+        Route { visited: 0, at: None }
+    }
+
+    fn successors(&self, route: &Route) -> Vec<(Route, usize)> {
+        (0..self.graph.num_nodes)
+            .filter(|&next| route.visited & (1 << next) == 0)
+            .map(|next| {
+                let dist = route.at.map_or(0, |at| self.graph.dists[at][next]);
+                (Route { visited: route.visited | (1 << next), at: Some(next) }, dist)
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, route: &Route) -> bool {
+        route.visited.count_ones() as usize == self.graph.num_nodes
+    }
+
+    fn estimate(&self, route: &Route) -> usize {
+        self.longest_leg.map_or(0, |leg| (self.graph.num_nodes - route.visited.count_ones() as usize) * leg)
+    }
+}
+
+mod parse {
+    use nom::bytes::complete::tag;
+    use nom::character::complete::alpha1;
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::sequence::{preceded, tuple};
+    use crate::parse::{lines, unsigned, IResult};
+    use super::Graph;
+
+    pub fn graph(input: &str) -> IResult<&str, Graph> {
+        map(
+            lines(
+                context(
+                    "distance",
+                    tuple((
+                        alpha1,
+                        preceded(tag(" to "), alpha1),
+                        preceded(tag(" = "), unsigned)
+                    ))
+                )
+            ),
+            Graph::from_dists
+        )(input)
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let graph = finish(input, parse::graph(input))?;
+        if graph.num_nodes >= usize::BITS as usize {
+            return Err(AocError::invalid_input(format!("too many cities: {}", graph.num_nodes)));
+        }
+        Ok(graph)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        dijkstra(&Tour { graph: input, longest_leg: None })?
+            .map(|found| found.cost.into())
+            .ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let longest_leg = input.dists.iter().flatten().max().copied();
+        branch_and_bound(&Tour { graph: input, longest_leg }, Objective::Maximise)?
+            .map(|found| found.cost.into())
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
+    crate::examples! {
+        Day09;
+        three_cities: TEST_INPUT => part1 = 605, part2 = 982;
+    }
+}
@@ -0,0 +1,67 @@
+use itertools::Itertools;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+fn look_and_say(ds: Vec<u8>) -> Vec<u8> {
+    ds.iter().dedup_with_count().flat_map(|(x, y)| [x as u8, *y]).collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<u8>, AocError> {
+    let input = input.trim();
+    input.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| AocError::parse_at(input, i, format!("expected a digit, found {c:?}")))
+        })
+        .collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut digits = input.clone();
+
+        for _ in 0..40 {
+            digits = look_and_say(digits);
+        }
+
+        Ok(digits.len().into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut digits = input.clone();
+
+        for _ in 0..50 {
+            digits = look_and_say(digits);
+        }
+
+        Ok(digits.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_look_and_say() {
+        let steps: Vec<Vec<u8>> = std::iter::successors(Some(vec![1]), |ds| Some(look_and_say(ds.clone())))
+            .take(6)
+            .collect();
+
+        assert_eq!(steps[1..], [vec![1, 1], vec![2, 1], vec![1, 2, 1, 1], vec![1, 1, 1, 2, 2, 1], vec![3, 1, 2, 2, 1, 1]]);
+    }
+
+    crate::examples! {
+        Day10;
+        one: "1" => part1 = 82350, part2 = 1166642;
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt::{Display, Formatter};
+use itertools::{Itertools, MinMaxResult};
+use crate::cancel;
+use crate::error::AocError;
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Password {
+    value: [u8; 8]
+}
+
+impl Display for Password {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s: String = self.value.iter()
+            .map(|&x| (x + b'a') as char)
+            .collect();
+        write!(f, "{s}")
+    }
+}
+
+impl Password {
+    fn from_string(s: &str) -> Result<Password, AocError> {
+        if let Some(i) = s.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(AocError::parse_at(s, i, "expected a lowercase letter"));
+        }
+
+        let value: [u8; 8] = s.bytes()
+            .map(|c| c - b'a')
+            .collect::<Vec<u8>>()
+            .try_into()
+            .map_err(|_| AocError::invalid_input("passwords must be exactly 8 letters long"))?;
+
+        Ok(Password { value })
+    }
+
+    fn iter(self) -> PasswordIter {
+        PasswordIter { cur: Some(self) }
+    }
+}
+
+struct PasswordIter { cur: Option<Password> }
+
+impl Iterator for PasswordIter {
+    type Item = Password;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pass = self.cur.as_mut()?;
+        let result = Some(*pass);
+
+        let mut j = 7;
+        while j > 0 && pass.value[j] == 25 {
+            pass.value[j] = 0;
+            j -= 1;
+        }
+
+        if pass.value[j] == 25 {
+            self.cur = None;
+        }
+        else {
+            pass.value[j] += 1;
+        }
+
+        result
+    }
+}
+
+fn is_valid(password: &Password) -> bool {
+    let contains_trip = password.value.iter().tuple_windows()
+        .any(|(&a, &b, &c)| c == b + 1 && b == a + 1);
+
+    let bad = [b'i', b'o', b'l'].map(|c| c - b'a');
+    let contains_bad = password.value.iter().any(|o| bad.contains(o));
+
+    let pair_indices = password.value.iter().tuple_windows().enumerate()
+        .filter(|(_, (&a, &b))| a == b)
+        .map(|(i, _)| i)
+        .minmax();
+
+    let contains_pairs = matches!(pair_indices, MinMaxResult::MinMax(a, b) if b > a + 1);
+
+    contains_trip && !contains_bad && contains_pairs
+}
+
+/// Valid passwords from `start` on, failing if the time runs out before the next is found.
+fn valid_passwords(start: Password) -> impl Iterator<Item = Result<Password, AocError>> {
+    start.iter()
+        .enumerate()
+        .map(|(i, pass)| cancel::tick(i).map(|_| pass))
+        .filter(|pass| pass.as_ref().map_or(true, is_valid))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Password;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Password::from_string(input.trim_end())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let pass = valid_passwords(*input).next().ok_or(AocError::NoSolution)??;
+        Ok(pass.to_string().into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let pass = valid_passwords(*input).nth(1).ok_or(AocError::NoSolution)??;
+        Ok(pass.to_string().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day11;
+        abcdefgh: "abcdefgh" => part1 = "abcdffaa";
+        #[ignore = "slow in debug builds"]
+        ghijklmn: "ghijklmn" => part1 = "ghjaabcc";
+    }
+}
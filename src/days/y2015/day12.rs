@@ -0,0 +1,166 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Eq, PartialEq)]
+pub enum Json {
+    JNumber(isize),
+    JString(String),
+    JArray(Vec<Json>),
+    JObject(HashMap<String, Json>)
+}
+use Json::*;
+
+impl Json {
+    fn sum(&self) -> isize {
+        match self {
+            JNumber(n) => *n,
+            JString(_) => 0,
+            JArray(elems) => elems.iter().map(|j| j.sum()).sum(),
+            JObject(objs) => objs.values().map(|j| j.sum()).sum()
+        }
+    }
+
+    fn sum_no_red(&self) -> isize {
+        match self {
+            JNumber(n) => *n,
+            JString(_) => 0,
+            JArray(elems) => elems.iter().map(|j| j.sum_no_red()).sum(),
+            JObject(objs) => {
+                if objs.values().contains(&JString("red".to_owned())) {
+                    0
+                }
+                else {
+                    objs.values().map(|j| j.sum_no_red()).sum()
+                }
+            }
+        }
+    }
+}
+
+mod parse {
+    use super::Json;
+    use super::Json::*;
+    use nom::branch::alt;
+    use nom::bytes::complete::is_not;
+    use nom::character::complete::{char, multispace0};
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::multi::separated_list0;
+    use nom::sequence::{delimited, pair, separated_pair, tuple};
+    use crate::parse::{signed, IResult};
+
+    fn jnumber(input: &str) -> IResult<&str, Json> {
+        map(signed, JNumber)(input)
+    }
+
+    fn string(input: &str) -> IResult<&str, String> {
+        // The input doesn't require any fancy worries about escaping, so... forget that.
+        map(
+            delimited(char('"'), is_not("\""), char('"')),
+            |s: &str| s.to_owned()
+        )(input)
+    }
+
+    fn jstring(input: &str) -> IResult<&str, Json> {
+        map(string, JString)(input)
+    }
+
+    fn jarray(input: &str) -> IResult<&str, Json> {
+        context(
+            "array",
+            map(
+                delimited(
+                    pair(char('['), multispace0),
+                    separated_list0(
+                        tuple((multispace0, char(','), multispace0)),
+                        json
+                    ),
+                    pair(multispace0, char(']'))
+                ),
+                JArray
+            )
+        )(input)
+    }
+
+    fn jobject_item(input: &str) -> IResult<&str, (String, Json)> {
+        context(
+            "object member",
+            separated_pair(
+                string,
+                tuple((multispace0, char(':'), multispace0)),
+                json
+            )
+        )(input)
+    }
+
+    fn jobject(input: &str) -> IResult<&str, Json> {
+        context(
+            "object",
+            map(
+                delimited(
+                    pair(char('{'), multispace0),
+                    separated_list0(
+                        tuple((multispace0, char(','), multispace0)),
+                        jobject_item
+                    ),
+                    pair(multispace0, char('}'))
+                ),
+                |kvs| JObject(kvs.into_iter().collect())
+            )
+        )(input)
+    }
+
+    pub fn json(input: &str) -> IResult<&str, Json> {
+        context(
+            "value",
+            alt((
+                jstring,
+                jnumber,
+                jobject,
+                jarray
+            ))
+        )(input)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Json;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse::json(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(input.sum().into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(input.sum_no_red().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day12;
+        array: "[1,2,3]" => part1 = 6, part2 = 6;
+        object: r#"{"a":2,"b":4}"# => part1 = 6;
+        nested_array: "[[[3]]]" => part1 = 3;
+        nested_object: r#"{"a":{"b":4},"c":-1}"# => part1 = 3;
+        cancelling_array: r#"{"a":[-1,1]}"# => part1 = 0;
+        cancelling_object: r#"[-1,{"a":1}]"# => part1 = 0;
+        empty_array: "[]" => part1 = 0;
+        empty_object: "{}" => part1 = 0;
+        red_object: r#"[1,{"c":"red","b":2},3]"# => part2 = 4;
+        red_everywhere: r#"{"d":"red","e":[1,2,3,4],"f":5}"# => part2 = 0;
+        red_in_array: r#"[1,"red",5]"# => part2 = 6;
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+pub struct Graph {
+    num_nodes: usize,
+    dists: Vec<Vec<isize>>
+}
+
+impl Graph {
+    fn from_input(input: Vec<(&str, &str, isize)>) -> Graph {
+        let nodes: Vec<&str> = input.iter()
+            .flat_map(|&(a, b, _)| [a, b])
+            .unique()
+            .collect();
+
+        let node_ids: HashMap<&str, usize> = nodes.iter().enumerate()
+            .map(|(i, &node)| (node, i))
+            .collect();
+
+        let num_nodes = nodes.len();
+        let mut dists = vec![vec![0; num_nodes]; num_nodes];
+
+        input.into_iter().for_each(|(a, b, net)| {
+            let i_a = node_ids[&a];
+            let i_b = node_ids[&b];
+            dists[i_a][i_b] += net;
+            dists[i_b][i_a] += net;
+        });
+
+        Graph { num_nodes, dists }
+    }
+}
+
+mod parse {
+    use crate::parse::{lines, unsigned, IResult};
+    use super::Graph;
+    use nom::branch::alt;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::{alpha1, char};
+    use nom::combinator::{map, value};
+    use nom::error::context;
+    use nom::sequence::{delimited, terminated, tuple};
+
+    fn line(input: &str) -> IResult<&str, (&str, &str, isize)> {
+        context(
+            "happiness rule",
+            map(
+                tuple((
+                    terminated(alpha1, tag(" would ")),
+                    context(
+                        "gain or lose",
+                        alt((
+                            value(1isize, tag("gain ")),
+                            value(-1isize, tag("lose "))
+                        ))
+                    ),
+                    unsigned::<isize>,
+                    delimited(tag(" happiness units by sitting next to "), alpha1, char('.'))
+                )),
+                |(a, signum, val, b)| (a, b, signum * val)
+            )
+        )(input)
+    }
+
+    pub fn graph(input: &str) -> IResult<&str, Graph> {
+        map(
+            lines(line),
+            |edges| Graph::from_input(edges)
+        )(input)
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let graph = finish(input, parse::graph(input))?;
+        if graph.num_nodes < 2 {
+            return Err(AocError::invalid_input(format!("too few guests to seat: {}", graph.num_nodes)));
+        }
+        Ok(graph)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let graph = input;
+
+        (1..graph.num_nodes).permutations(graph.num_nodes - 1)
+            .map(|p| {
+                let zero_costs = graph.dists[0][p[0]] + graph.dists[0][*p.last().unwrap()];
+                p.into_iter().tuple_windows()
+                    .map(|(a, b)| graph.dists[a][b])
+                    .sum::<isize>() + zero_costs
+            })
+            .max()
+            .map(Answer::from)
+            .ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let graph = input;
+
+        (0..graph.num_nodes).permutations(graph.num_nodes)
+            .map(|p| {
+                p.into_iter().tuple_windows()
+                    .map(|(a, b)| graph.dists[a][b])
+                    .sum::<isize>()
+            })
+            .max()
+            .map(Answer::from)
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        assert_eq!(Day13::part1(&Day13::parse(TEST_INPUT)?)?, 330.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_one_guest() {
+        let input = "Alice would gain 5 happiness units by sitting next to Alice.";
+        assert_eq!(Day13::parse(input).err(), Some(AocError::invalid_input("too few guests to seat: 1")));
+    }
+}
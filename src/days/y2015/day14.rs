@@ -0,0 +1,204 @@
+use std::cmp::min;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Reindeer {
+    speed: usize,
+    stamina: usize,
+    rest_period: usize
+}
+
+impl Reindeer {
+    fn distance(&self, t: usize) -> usize {
+        let full_periods = t / (self.rest_period + self.stamina);
+        let remaining_secs = min(self.stamina, t % (self.rest_period + self.stamina));
+
+        full_periods * self.speed * self.stamina + remaining_secs * self.speed
+    }
+}
+
+enum ReindeerStatus {
+    Resting(usize),
+    Flying(usize)
+}
+use ReindeerStatus::*;
+
+struct Trajectory {
+    reindeer: Reindeer,
+    status: ReindeerStatus,
+    position: usize
+}
+
+impl Trajectory {
+    fn of(reindeer: Reindeer) -> Trajectory {
+        let status = Flying(reindeer.stamina);
+        let position = 0;
+        Trajectory { reindeer, status, position }
+    }
+}
+
+impl Iterator for Trajectory {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.status {
+            Resting(1) => { self.status = Flying(self.reindeer.stamina); },
+            Resting(n) => { self.status = Resting(n - 1); },
+            Flying(1) => {
+                self.status = match self.reindeer.rest_period {
+                    0 => Flying(self.reindeer.stamina),
+                    rest_period => Resting(rest_period)
+                };
+                self.position += self.reindeer.speed;
+            },
+            Flying(n) => {
+                self.status = Flying(n - 1);
+                self.position += self.reindeer.speed;
+            }
+        }
+
+        Some(self.position)
+    }
+}
+
+struct Race {
+    trajectories: Vec<Trajectory>,
+    points: Vec<usize>
+}
+
+impl Race {
+    fn new(reindeer: Vec<Reindeer>) -> Race {
+        let trajectories: Vec<Trajectory> = reindeer.into_iter()
+            .map(Trajectory::of)
+            .collect();
+
+        let points = vec![0; trajectories.len()];
+        Race { trajectories, points }
+    }
+}
+
+impl Iterator for Race {
+    type Item = (Vec<usize>, Vec<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let positions: Vec<usize> = self.trajectories.iter_mut()
+            .map(|t| t.next().unwrap())
+            .collect();
+
+        let best = *positions.iter().max().unwrap();
+        for (i, &pos) in positions.iter().enumerate() {
+            if pos == best {
+                self.points[i] += 1;
+            }
+        }
+
+        Some((positions, self.points.clone()))
+    }
+}
+
+/// How long the race lasts, in seconds.
+const RACE_TIME: usize = 2503;
+
+fn furthest_distance(reindeer: &[Reindeer], t: usize) -> Option<usize> {
+    reindeer.iter().map(|r| r.distance(t)).max()
+}
+
+/// The winner's points after `t` seconds, awarding a point to each leader every second.
+fn most_points(reindeer: &[Reindeer], t: usize) -> Option<usize> {
+    let (_, scores) = Race::new(reindeer.to_vec()).nth(t.checked_sub(1)?)?;
+    scores.into_iter().max()
+}
+
+mod parse {
+    use nom::bytes::complete::tag;
+    use nom::character::complete::alpha1;
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::sequence::{delimited, preceded, tuple};
+    use crate::parse::{lines, unsigned, IResult};
+    use super::Reindeer;
+
+    fn reindeer(input: &str) -> IResult<&str, Reindeer> {
+        context(
+            "reindeer",
+            map(
+                tuple((
+                    preceded(tuple((alpha1, tag(" can fly "))), unsigned),
+                    preceded(tag(" km/s for "), unsigned),
+                    delimited(tag(" seconds, but then must rest for "), unsigned, tag(" seconds."))
+                )),
+                |(speed, stamina, rest_period)| {
+                    Reindeer { speed, stamina, rest_period }
+                }
+            )
+        )(input)
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Reindeer>> {
+        lines(reindeer)(input)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let reindeer = finish(input, parse::input(input))?;
+        if reindeer.iter().any(|r| r.stamina == 0) {
+            return Err(AocError::invalid_input("a reindeer can't fly for 0 seconds"));
+        }
+        Ok(reindeer)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        furthest_distance(input, RACE_TIME)
+            .map(Answer::from)
+            .ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        most_points(input, RACE_TIME)
+            .map(Answer::from)
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "\
+Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        let reindeer = Day14::parse(TEST_INPUT)?;
+        assert_eq!(furthest_distance(&reindeer, 1000), Some(1120));
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), AocError> {
+        let reindeer = Day14::parse(TEST_INPUT)?;
+        assert_eq!(most_points(&reindeer, 1), Some(1));
+        assert_eq!(most_points(&reindeer, 140), Some(139));
+        assert_eq!(most_points(&reindeer, 1000), Some(689));
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_rest() -> Result<(), AocError> {
+        let reindeer = Day14::parse("Comet can fly 14 km/s for 10 seconds, but then must rest for 0 seconds.")?;
+        assert_eq!(furthest_distance(&reindeer, 20), Some(280));
+        assert_eq!(most_points(&reindeer, 20), Some(20));
+
+        let exhausted = "Comet can fly 14 km/s for 0 seconds, but then must rest for 0 seconds.";
+        assert_eq!(Day14::parse(exhausted).err(), Some(AocError::invalid_input("a reindeer can't fly for 0 seconds")));
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+use std::cmp::max;
+use std::ops::{Add, Mul};
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Ingredient {
+    tsps: isize,
+    cals: isize,
+    capacity: isize,
+    durability: isize,
+    flavor: isize,
+    texture: isize
+}
+
+impl Ingredient {
+    fn score(&self) -> isize {
+        [self.capacity, self.durability, self.flavor, self.texture].iter()
+            .map(|&x| max(x, 0))
+            .product()
+    }
+}
+
+impl Mul<isize> for Ingredient {
+    type Output = Ingredient;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Ingredient {
+            tsps: self.tsps * rhs,
+            cals: self.cals * rhs,
+            capacity: self.capacity * rhs,
+            durability: self.durability * rhs,
+            flavor: self.flavor * rhs,
+            texture: self.texture * rhs
+        }
+    }
+}
+
+impl Add for Ingredient {
+    type Output = Ingredient;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Ingredient {
+            tsps: self.tsps + rhs.tsps,
+            cals: self.cals + rhs.cals,
+            capacity: self.capacity + rhs.capacity,
+            durability: self.durability + rhs.durability,
+            flavor: self.flavor + rhs.flavor,
+            texture: self.texture + rhs.texture
+        }
+    }
+}
+
+mod parse {
+    use nom::bytes::complete::tag;
+    use nom::character::complete::alpha1;
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::sequence::{delimited, preceded, terminated, tuple};
+    use crate::parse::{lines, signed, IResult};
+    use super::Ingredient;
+
+    fn ingredient(input: &str) -> IResult<&str, Ingredient> {
+        context(
+            "ingredient",
+            map(
+                tuple((
+                    terminated(alpha1, tag(": ")),
+                    delimited(tag("capacity "), signed, tag(", ")),
+                    delimited(tag("durability "), signed, tag(", ")),
+                    delimited(tag("flavor "), signed, tag(", ")),
+                    delimited(tag("texture "), signed, tag(", ")),
+                    preceded(tag("calories "), signed)
+                )),
+                |(_, capacity, durability, flavor, texture, cals)| {
+                    Ingredient { tsps: 1, cals, capacity, durability, flavor, texture }
+                }
+            )
+        )(input)
+    }
+
+    pub fn input(input: &str) -> IResult<&str, Vec<Ingredient>> {
+        lines(ingredient)(input)
+    }
+}
+
+struct Choice {
+    i: usize,
+    cur: Ingredient
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Ingredient>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let ingredients = finish(input, parse::input(input))?;
+
+        if ingredients.len() < 2 {
+            return Err(AocError::invalid_input("need at least two ingredients"));
+        }
+
+        Ok(ingredients)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let ingredients = input;
+        let n = ingredients.len();
+
+        let mut choices = Vec::new();
+        choices.push(Choice { i: 0, cur: Ingredient::default() });
+        let mut best = 0;
+
+        while let Some(&Choice { i, cur }) = choices.last() {
+            if i == n - 2 {
+                let rem = 100 - cur.tsps;
+                best = max(best, (ingredients[i+1] * rem + cur).score());
+                while let Some(&Choice { cur: Ingredient { tsps: 100, ..}, ..}) = choices.last() {
+                    choices.pop();
+                }
+                if let Some(last) = choices.last_mut() {
+                    last.cur = last.cur + ingredients[last.i];
+
+                    if last.i == 0 && last.cur.tsps > 100 {
+                        break
+                    }
+                }
+            }
+            else {
+                choices.push(Choice { i: i + 1, cur });
+            }
+        }
+
+        Ok(best.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let ingredients = input;
+        let n = ingredients.len();
+
+        let mut choices = Vec::new();
+        choices.push(Choice { i: 0, cur: Ingredient::default() });
+        let mut best = 0;
+
+        while let Some(&Choice { i, cur }) = choices.last() {
+            if i == n - 2 {
+                let rem = 100 - cur.tsps;
+                let overall = cur + ingredients[i + 1] * rem;
+
+                if overall.cals == 500 {
+                    best = max(best, (ingredients[i+1] * rem + cur).score());
+                }
+                while let Some(&Choice { i, cur }) = choices.last() {
+                    if cur.tsps == 100 || cur.cals + ingredients[i].cals > 500 {
+                        choices.pop();
+                    }
+                    else {
+                        break
+                    }
+                }
+
+                if let Some(last) = choices.last_mut() {
+                    last.cur = last.cur + ingredients[last.i];
+                }
+                else {
+                    break
+                }
+            }
+            else {
+                choices.push(Choice { i: i + 1, cur });
+            }
+        }
+
+        Ok(best.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "\
+Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+    crate::examples! {
+        Day15;
+        two_ingredients: TEST_INPUT => part1 = 62842880, part2 = 57600000;
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+pub type Sue<'a> = HashMap<&'a str, usize>;
+
+/// The compounds the MFCSAM reports on; no other properties can appear in a Sue's description.
+const COMPOUNDS: [&str; 10] = [
+    "children", "cats", "samoyeds", "pomeranians", "akitas",
+    "vizslas", "goldfish", "trees", "cars", "perfumes"
+];
+
+mod parse {
+    use super::{Sue, COMPOUNDS};
+    use nom::bytes::complete::tag;
+    use nom::character::complete::alpha1;
+    use nom::combinator::{cut, map, verify};
+    use nom::error::context;
+    use nom::multi::separated_list1;
+    use nom::sequence::{preceded, separated_pair, tuple};
+    use crate::parse::{lines, unsigned, IResult};
+
+    fn property(input: &str) -> IResult<&str, (&str, usize)> {
+        separated_pair(
+            context("compound", verify(alpha1, |s: &str| COMPOUNDS.contains(&s))),
+            tag(": "),
+            unsigned
+        )(input)
+    }
+
+    fn sue(input: &str) -> IResult<&str, Sue<'_>> {
+        context(
+            "aunt Sue",
+            map(
+                preceded(
+                    tuple((tag("Sue "), unsigned::<usize>, tag(": "))),
+                    separated_list1(tag(", "), cut(property))
+                ),
+                |props| props.into_iter().collect()
+            )
+        )(input)
+    }
+
+    pub fn sues(input: &str) -> IResult<&str, Vec<Sue<'_>>> {
+        lines(sue)(input)
+    }
+}
+
+fn matches_exact(poss: &Sue) -> bool {
+    poss.iter()
+        .all(|(&k, &v)| {
+            v == match k {
+                "children" => 3,
+                "cats" =>  7,
+                "samoyeds" =>  2,
+                "pomeranians" => 3,
+                "akitas" => 0,
+                "vizslas" => 0,
+                "goldfish" => 5,
+                "trees" => 3,
+                "cars" => 2,
+                "perfumes" => 1,
+                _ => return false
+            }
+        })
+}
+
+fn matches_range(poss: &Sue) -> bool {
+    poss.iter()
+        .all(|(&k, &v)| {
+            match k {
+                "children" => v == 3,
+                "cats" =>  v > 7,
+                "samoyeds" =>  v == 2,
+                "pomeranians" => v < 3,
+                "akitas" => v == 0,
+                "vizslas" => v == 0,
+                "goldfish" => v < 5,
+                "trees" => v > 3,
+                "cars" => v == 2,
+                "perfumes" => v == 1,
+                _ => false
+            }
+        })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Vec<Sue<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse::sues(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        input.iter()
+            .position(matches_exact)
+            .map(|i| Answer::from(i + 1))
+            .ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        input.iter()
+            .position(matches_range)
+            .map(|i| Answer::from(i + 1))
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Not from the puzzle: Sue 2 matches the readings exactly, while Sue 3 only matches once
+    // cats and trees are read as lower bounds and pomeranians as an upper bound.
+    const TEST_INPUT: &str = "\
+Sue 1: goldfish: 6, trees: 9, akitas: 0
+Sue 2: children: 3, cats: 7, samoyeds: 2
+Sue 3: cats: 8, trees: 4, pomeranians: 1";
+
+    crate::examples! {
+        Day16;
+        three_sues: TEST_INPUT => part1 = 2, part2 = 3;
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use crate::error::AocError;
+use crate::parse::{finish, lines, unsigned};
+use crate::solution::{Answer, Solution};
+
+fn count_ways(sizes: &[usize], target: usize) -> Vec<usize> {
+    let mut cur = vec![HashMap::from([(0, 1)])];
+
+    for &size in sizes {
+        let mut next_cur = cur.clone();
+        next_cur.push(HashMap::new());
+
+        for (num_containers, combs) in cur.iter().enumerate() {
+            for (&total, &count) in combs.iter() {
+                let entry = next_cur[num_containers + 1]
+                    .entry(total + size)
+                    .or_insert(0);
+
+                *entry += count;
+            }
+        }
+
+        cur = next_cur;
+    }
+
+    cur.iter().map(|c| c.get(&target).cloned().unwrap_or(0)).collect()
+}
+
+/// Litres of eggnog to store.
+const EGGNOG: usize = 150;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, lines(unsigned)(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let counts = count_ways(input, EGGNOG);
+        Ok(counts.into_iter().sum::<usize>().into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let counts = count_ways(input, EGGNOG);
+
+        counts.into_iter()
+            .find(|&n| n > 0)
+            .map(Answer::from)
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_ways() {
+        // Ways to store 25 litres, by number of containers used.
+        assert_eq!(count_ways(&[20, 15, 10, 5, 5], 25), [0, 0, 3, 1, 0, 0]);
+    }
+}
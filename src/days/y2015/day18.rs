@@ -0,0 +1,97 @@
+use nom::branch::alt;
+use nom::character::complete::char;
+use nom::combinator::value;
+use crate::error::AocError;
+use crate::grid::{Grid, SURROUNDING};
+use crate::parse::{finish, grid};
+use crate::solution::{Answer, Solution};
+
+#[derive(Clone)]
+pub struct GameOfLife {
+    lights: Grid<bool>
+}
+
+impl GameOfLife {
+    fn update(&mut self) {
+        let lights = &self.lights;
+        self.lights = Grid::from_fn(lights.rows(), lights.cols(), |p| {
+            let on_neighbors = lights.neighbors(p, &SURROUNDING).filter(|&q| lights[q]).count();
+            on_neighbors == 3 || (lights[p] && on_neighbors == 2)
+        });
+    }
+}
+
+fn parse_board(input: &str) -> Result<GameOfLife, AocError> {
+    let lights = finish(input, grid(alt((value(true, char('#')), value(false, char('.')))))(input))?;
+    Ok(GameOfLife { lights })
+}
+
+const STEPS: usize = 100;
+
+/// Number of lights on after `steps` updates, optionally with the four corners stuck on.
+fn lights_on_after(game: &GameOfLife, steps: usize, stuck_corners: bool) -> usize {
+    let mut game = game.clone();
+
+    fn corners_on(g: &mut GameOfLife) {
+        let (last_row, last_col) = (g.lights.rows() as isize - 1, g.lights.cols() as isize - 1);
+        for corner in [(0, 0), (0, last_col), (last_row, 0), (last_row, last_col)] {
+            g.lights[corner] = true;
+        }
+    }
+
+    if stuck_corners {
+        corners_on(&mut game);
+    }
+
+    for _ in 0..steps {
+        game.update();
+        if stuck_corners {
+            corners_on(&mut game);
+        }
+    }
+
+    game.lights.values().filter(|&&on| on).count()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = GameOfLife;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_board(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(lights_on_after(input, STEPS, false).into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(lights_on_after(input, STEPS, true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "\
+.#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+    #[test]
+    fn test_part1() -> Result<(), AocError> {
+        assert_eq!(lights_on_after(&Day18::parse(TEST_INPUT)?, 4, false), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<(), AocError> {
+        assert_eq!(lights_on_after(&Day18::parse(TEST_INPUT)?, 5, true), 17);
+        Ok(())
+    }
+}
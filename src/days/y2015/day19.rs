@@ -1,13 +1,15 @@
 use std::collections::{HashMap, HashSet};
-use adventofcode2015::build_main;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-struct Rule<'a> {
+pub struct Rule<'a> {
     from: &'a str,
     to: Vec<&'a str>
 }
 
-struct Grammar<'a> {
+pub struct Grammar<'a> {
     pub rules: Vec<Rule<'a>>,
     pub start_rule_id: usize,
     pub rule_ids_by_from: HashMap<&'a str, Vec<usize>>
@@ -28,7 +30,7 @@ impl<'a> Grammar<'a> {
 mod earley {
     use std::ops::Index;
     use itertools::{chain, Itertools};
-    use crate::Grammar;
+    use super::Grammar;
 
     #[derive(Copy, Clone)]
     struct BackPointer {
@@ -240,12 +242,13 @@ mod earley {
 mod parse {
     use nom::branch::alt;
     use nom::bytes::complete::tag;
-    use nom::character::complete::{multispace1, newline, one_of, char as character};
+    use nom::character::complete::{multispace1, one_of, char as character};
     use nom::combinator::{map, recognize};
-    use nom::IResult;
-    use nom::multi::{many0, many1, separated_list1};
+    use nom::error::context;
+    use nom::multi::{many0, many1};
     use nom::sequence::{pair, separated_pair};
-    use crate::{Grammar, Rule};
+    use crate::parse::{lines, IResult};
+    use super::{Grammar, Rule};
 
     fn upper_char(input: &str) -> IResult<&str, char> {
         one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ")(input)
@@ -256,26 +259,32 @@ mod parse {
     }
 
     fn symbol(input: &str) -> IResult<&str, &str> {
-        alt((
-            recognize(pair(upper_char, many0(lower_char))),
-            recognize(character('e'))
-        ))(input)
+        context(
+            "element",
+            alt((
+                recognize(pair(upper_char, many0(lower_char))),
+                recognize(character('e'))
+            ))
+        )(input)
     }
 
-    fn rule(input: &str) -> IResult<&str, Rule> {
-        map(
-            separated_pair(
-                symbol,
-                tag(" => "),
-                many1(symbol)
-            ),
-            |(from, to)| Rule { from: from, to: to }
+    fn rule(input: &str) -> IResult<&str, Rule<'_>> {
+        context(
+            "replacement",
+            map(
+                separated_pair(
+                    symbol,
+                    tag(" => "),
+                    many1(symbol)
+                ),
+                |(from, to)| Rule { from, to }
+            )
         )(input)
     }
 
-    fn grammar(input: &str) -> IResult<&str, Grammar> {
+    fn grammar(input: &str) -> IResult<&str, Grammar<'_>> {
         map(
-            separated_list1(newline, rule),
+            lines(rule),
             |mut rules| {
                 let start_rule = Rule { from: "S0", to: vec!["e"] };
                 rules.push(start_rule);
@@ -284,40 +293,49 @@ mod parse {
         )(input)
     }
 
-    pub fn input(input: &str) -> IResult<&str, (Grammar, Vec<&str>)> {
-        separated_pair(grammar, multispace1, many1(symbol))(input)
+    pub fn input(input: &str) -> IResult<&str, (Grammar<'_>, Vec<&str>)> {
+        separated_pair(grammar, multispace1, context("molecule", many1(symbol)))(input)
     }
 }
 
-fn part1(input: &str) -> usize {
-    let (grammar, target) = parse::input(input).unwrap().1;
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (Grammar<'a>, Vec<&'a str>);
 
-    let mut seen = HashSet::new();
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse::input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let (grammar, target) = input;
 
-    for rule in grammar.rules {
+        let mut seen = HashSet::new();
 
-        for i in 0..target.len() {
-            if target[i] == rule.from {
-                let mut new: Vec<&str> = Vec::new();
-                new.extend(target[..i].iter());
-                new.extend(rule.to.iter());
-                new.extend(target[i+1..].iter());
-                seen.insert(new);
+        for rule in &grammar.rules {
+            for i in 0..target.len() {
+                if target[i] == rule.from {
+                    let mut new: Vec<&str> = Vec::new();
+                    new.extend(target[..i].iter());
+                    new.extend(rule.to.iter());
+                    new.extend(target[i+1..].iter());
+                    seen.insert(new);
+                }
             }
         }
+        Ok(seen.len().into())
     }
-    seen.len()
-}
 
-fn part2(input: &str) -> usize {
-    let (grammar, target) = parse::input(input).unwrap().1;
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let (grammar, target) = input;
 
-    earley::min_rules(&grammar, &target).unwrap() - 1
+        // The grammar's extra start rule `S0 => e` is counted, but isn't a real replacement.
+        earley::min_rules(grammar, target)
+            .map(|n| Answer::from(n - 1))
+            .ok_or(AocError::NoSolution)
+    }
 }
 
-
-build_main!("day19.txt", "Part 1" => part1, "Part 2" => part2);
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,14 +357,16 @@ O => HH
 HOHOHO";
 
     #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT1), 4);
-        assert_eq!(part1(TEST_INPUT2), 7);
+    fn test_part1() -> Result<(), AocError> {
+        assert_eq!(Day19::part1(&Day19::parse(TEST_INPUT1)?)?, 4.into());
+        assert_eq!(Day19::part1(&Day19::parse(TEST_INPUT2)?)?, 7.into());
+        Ok(())
     }
 
     #[test]
-    fn test_part2() {
-        assert_eq!(part2(TEST_INPUT1), 3);
-        assert_eq!(part2(TEST_INPUT2), 6);
+    fn test_part2() -> Result<(), AocError> {
+        assert_eq!(Day19::part2(&Day19::parse(TEST_INPUT1)?)?, 3.into());
+        assert_eq!(Day19::part2(&Day19::parse(TEST_INPUT2)?)?, 6.into());
+        Ok(())
     }
-}
\ No newline at end of file
+}
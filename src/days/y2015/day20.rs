@@ -0,0 +1,76 @@
+use crate::cancel;
+use crate::error::AocError;
+use crate::parse::{finish, unsigned};
+use crate::solution::{Answer, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, unsigned(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let target = input / 10;
+        let mut best = target;
+        let mut i = 1;
+        let mut presents = vec![0; target + 1];
+
+        while i < best {
+            cancel::tick(i)?;
+            let mut ki = i;
+            while ki <= best {
+                presents[ki] += i;
+                if presents[ki] >= target {
+                    best = ki;
+                }
+
+                ki += i;
+            }
+
+            i += 1;
+        }
+
+        Ok(best.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let target = *input;
+        let mut best = target;
+        let mut i = 1;
+        let mut presents = vec![0; target + 1];
+
+        while i < best {
+            cancel::tick(i)?;
+            let mut k = 1;
+            let mut ki = i;
+            while ki <= best && k <= 50 {
+                presents[ki] += 11 * i;
+                if presents[ki] >= target {
+                    best = ki;
+                }
+
+                ki += i;
+                k += 1;
+            }
+
+            i += 1;
+        }
+
+        Ok(best.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day20;
+        house_4: "70" => part1 = 4;
+        house_6: "120" => part1 = 6;
+        house_8: "150" => part1 = 8;
+    }
+}
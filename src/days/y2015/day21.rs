@@ -0,0 +1,251 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Add;
+use itertools::{iproduct, Itertools};
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Stats {
+    hp: usize,
+    damage: usize,
+    armor: usize,
+    cost: usize
+}
+
+impl Stats {
+    pub fn cost(&self) -> usize {
+        self.cost
+    }
+
+    /// The damage each of our hits does to `enemy`: at least 1, however much armor it has.
+    fn damage_to(&self, enemy: &Stats) -> usize {
+        self.damage.saturating_sub(enemy.armor).max(1)
+    }
+
+    fn beats(&self, enemy: &Stats) -> bool {
+        enemy.hp.div_ceil(self.damage_to(enemy)) <= self.hp.div_ceil(enemy.damage_to(self))
+    }
+
+    /// Plays out the fight against `enemy` a hit at a time, us striking first.
+    pub fn fight(&self, enemy: &Stats) -> Battle {
+        let mut hp = [self.hp, enemy.hp];
+        let damage = [self.damage_to(enemy), enemy.damage_to(self)];
+        let mut hits = Vec::new();
+
+        for attacker in [0, 1].into_iter().cycle() {
+            let defender = 1 - attacker;
+            hp[defender] = hp[defender].saturating_sub(damage[attacker]);
+            hits.push(Hit { by_player: attacker == 0, damage: damage[attacker], hp_left: hp[defender] });
+            if hp[defender] == 0 {
+                break;
+            }
+        }
+
+        Battle { player: *self, boss: *enemy, hits }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} hit points, {} damage, {} armor", self.hp, self.damage, self.armor)
+    }
+}
+
+impl Add for Stats {
+    type Output = Stats;
+    fn add(self, rhs: Self) -> Self::Output {
+        Stats {
+            hp: self.hp + rhs.hp,
+            damage: self.damage + rhs.damage,
+            armor: self.armor + rhs.armor,
+            cost: self.cost + rhs.cost
+        }
+    }
+}
+
+/// One hit in a fight: who struck, how hard, and the hit points the other has left.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Hit {
+    pub by_player: bool,
+    pub damage: usize,
+    pub hp_left: usize
+}
+
+/// A fight played out, hit by hit until one side is down.
+pub struct Battle {
+    player: Stats,
+    boss: Stats,
+    hits: Vec<Hit>
+}
+
+impl Battle {
+    pub fn hits(&self) -> &[Hit] {
+        &self.hits
+    }
+
+    pub fn player_wins(&self) -> bool {
+        self.hits.last().is_some_and(|hit| hit.by_player)
+    }
+}
+
+impl Display for Battle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Turn  Attacker  Damage  Player HP  Boss HP")?;
+        writeln!(f, "{:>4}  {:8}  {:>6}  {:>9}  {:>7}", 0, "", "", self.player.hp, self.boss.hp)?;
+        let (mut player_hp, mut boss_hp) = (self.player.hp, self.boss.hp);
+        for (turn, hit) in self.hits.iter().enumerate() {
+            let attacker = if hit.by_player {
+                boss_hp = hit.hp_left;
+                "player"
+            }
+            else {
+                player_hp = hit.hp_left;
+                "boss"
+            };
+            writeln!(f, "{:>4}  {attacker:8}  {:>6}  {player_hp:>9}  {boss_hp:>7}", turn + 1, hit.damage)?;
+        }
+        writeln!(f, "The {} wins", if self.player_wins() { "player" } else { "boss" })
+    }
+}
+
+fn choices(from: &[Stats], sizes: impl Iterator<Item=usize>) -> Vec<Stats> {
+    let mut result = Vec::new();
+    for size in sizes {
+        for combs in from.iter().cloned().combinations(size) {
+            result.push(combs.into_iter().reduce(|x, y| x + y).unwrap_or_default());
+        }
+    }
+
+    result
+}
+
+mod parse {
+    use nom::bytes::complete::tag;
+    use nom::character::complete::newline;
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::sequence::{delimited, preceded, tuple};
+    use crate::parse::{unsigned, IResult};
+    use super::Stats;
+
+    pub fn input(input: &str) -> IResult<&str, Stats> {
+        map(
+            tuple((
+                context("hit points", delimited(tag("Hit Points: "), unsigned, newline)),
+                context("damage", delimited(tag("Damage: "), unsigned, newline)),
+                context("armor", preceded(tag("Armor: "), unsigned))
+            )),
+            |(hp, damage, armor)| Stats { hp, damage, armor, cost: 0 }
+        )(input)
+    }
+}
+
+const WEAPONS: [Stats; 5] = [
+    Stats { hp: 0, damage: 4, armor: 0, cost: 8 },
+    Stats { hp: 0, damage: 5, armor: 0, cost: 10 },
+    Stats { hp: 0, damage: 6, armor: 0, cost: 25 },
+    Stats { hp: 0, damage: 7, armor: 0, cost: 40 },
+    Stats { hp: 0, damage: 8, armor: 0, cost: 74 }
+];
+
+const ARMOR: [Stats; 5] = [
+    Stats { hp: 0, damage: 0, armor: 1, cost: 13 },
+    Stats { hp: 0, damage: 0, armor: 2, cost: 31 },
+    Stats { hp: 0, damage: 0, armor: 3, cost: 53 },
+    Stats { hp: 0, damage: 0, armor: 4, cost: 75 },
+    Stats { hp: 0, damage: 0, armor: 5, cost: 102 }
+];
+
+const RINGS: [Stats; 6] = [
+    Stats { hp: 0, damage: 1, armor: 0, cost: 25 },
+    Stats { hp: 0, damage: 2, armor: 0, cost: 50 },
+    Stats { hp: 0, damage: 3, armor: 0, cost: 100 },
+    Stats { hp: 0, damage: 0, armor: 1, cost: 20 },
+    Stats { hp: 0, damage: 0, armor: 2, cost: 40 },
+    Stats { hp: 0, damage: 0, armor: 3, cost: 80 },
+];
+
+/// Everything the shop lets us kit ourselves out with: one weapon, up to one armor and up to two
+/// rings, on top of our 100 hit points.
+fn loadouts() -> impl Iterator<Item = Stats> {
+    let armor_opts: Vec<Stats> = choices(&ARMOR, 0..=1);
+    let ring_opts = choices(&RINGS, 0..=2);
+
+    iproduct!(WEAPONS, armor_opts, ring_opts)
+        .map(|(w, a, r)| {
+            let mut total = w + a + r;
+            total.hp = 100;
+            total
+        })
+}
+
+/// The cheapest loadout that beats `boss`, if any does.
+pub fn cheapest_win(boss: &Stats) -> Option<Stats> {
+    loadouts().filter(|s| s.beats(boss)).min_by_key(|s| s.cost)
+}
+
+/// The most expensive loadout that loses to `boss`, if any does.
+pub fn dearest_loss(boss: &Stats) -> Option<Stats> {
+    loadouts().filter(|s| !s.beats(boss)).max_by_key(|s| s.cost)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Stats;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse::input(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        cheapest_win(input)
+            .map(|s| s.cost.into())
+            .ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        dearest_loss(input)
+            .map(|s| s.cost.into())
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beats() -> Result<(), AocError> {
+        let boss = Day21::parse("Hit Points: 12\nDamage: 7\nArmor: 2")?;
+        let player = Stats { hp: 8, damage: 5, armor: 5, cost: 0 };
+
+        assert!(player.beats(&boss));
+        assert!(!Stats { hp: 6, ..player }.beats(&boss));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fight() -> Result<(), AocError> {
+        let boss = Day21::parse("Hit Points: 12\nDamage: 7\nArmor: 2")?;
+        let player = Stats { hp: 8, damage: 5, armor: 5, cost: 0 };
+
+        let battle = player.fight(&boss);
+        let hp_left = battle.hits().iter().map(|hit| (hit.by_player, hit.hp_left)).collect_vec();
+        assert_eq!(hp_left, [(true, 9), (false, 6), (true, 6), (false, 4), (true, 3), (false, 2), (true, 0)]);
+        assert!(battle.player_wins());
+        assert!(!Stats { hp: 6, ..player }.fight(&boss).player_wins());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fight_agrees_with_beats() {
+        for (hp, damage, armor) in iproduct!([1, 50, 100, 104, 109], [0, 5, 8, 12], [0, 1, 3, 6]) {
+            let boss = Stats { hp, damage, armor, cost: 0 };
+            for player in loadouts() {
+                assert_eq!(player.fight(&boss).player_wins(), player.beats(&boss), "{player} against {boss}");
+            }
+        }
+    }
+}
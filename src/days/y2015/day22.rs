@@ -0,0 +1,745 @@
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::search::{dijkstra, Problem};
+use crate::solution::{Answer, Solution};
+
+/// The most spells a spellbook can hold, so that the effect timers fit in [`Stats`].
+const MAX_SPELLS: usize = 8;
+
+/// The puzzle's spells, in the format [`Spellbook::parse`] reads.
+pub const STANDARD_SPELLBOOK: &str = "\
+Magic Missile: 53 mana; 4 damage
+Drain: 73 mana; 2 damage, heal 2
+Shield: 113 mana; 6 turns of 7 armor
+Poison: 173 mana; 6 turns of 3 damage
+Recharge: 229 mana; 5 turns of 101 mana";
+
+/// What an effect does at the start of each turn, the player's and the boss's, while it lasts.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct Effect {
+    pub turns: u8,
+    pub damage: usize,
+    pub heal: usize,
+    /// Armor for the player for the rest of the turn.
+    pub armor: usize,
+    pub mana: usize
+}
+
+impl Effect {
+    /// Applies a turn of the effect, giving the armor it lends.
+    fn tick(&self, stats: &mut Stats) -> usize {
+        stats.boss_hp = stats.boss_hp.saturating_sub(self.damage);
+        stats.player_hp += self.heal;
+        stats.player_mana += self.mana;
+        self.armor
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Spell {
+    pub name: String,
+    pub cost: usize,
+    /// Damage dealt to the boss when the spell is cast.
+    pub damage: usize,
+    /// Hit points the player gains when the spell is cast.
+    pub heal: usize,
+    /// The effect the spell starts.  It can't be cast again while that's still going.
+    pub effect: Option<Effect>
+}
+
+/// The spells the player can cast, in order of cost.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Spellbook {
+    spells: Vec<Spell>
+}
+
+impl Spellbook {
+    pub fn new(mut spells: Vec<Spell>) -> Result<Spellbook, AocError> {
+        if spells.len() > MAX_SPELLS {
+            return Err(AocError::invalid_input(format!("{} spells, but at most {MAX_SPELLS} are allowed", spells.len())));
+        }
+        if let Some(name) = spells.iter().map(|spell| &spell.name).duplicates().next() {
+            return Err(AocError::invalid_input(format!("more than one spell called {name}")));
+        }
+        if let Some(spell) = spells.iter().find(|spell| spell.effect.is_some_and(|effect| effect.turns == 0)) {
+            return Err(AocError::invalid_input(format!("{}'s effect lasts no turns", spell.name)));
+        }
+
+        spells.sort_by_key(|spell| spell.cost);
+        Ok(Spellbook { spells })
+    }
+
+    /// Reads a spellbook with a spell on each line, as in [`STANDARD_SPELLBOOK`].
+    pub fn parse(text: &str) -> Result<Spellbook, AocError> {
+        Spellbook::new(finish(text, parse::spellbook(text))?)
+    }
+
+    pub fn spells(&self) -> &[Spell] {
+        &self.spells
+    }
+
+    /// The place of the spell called `name`, ignoring case.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.spells.iter().position(|spell| spell.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl Default for Spellbook {
+    fn default() -> Self {
+        Spellbook::parse(STANDARD_SPELLBOOK).expect("the standard spellbook is valid")
+    }
+}
+
+/// The player's stats at the start of a fight.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Player {
+    pub hp: usize,
+    pub mana: usize
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Player { hp: 50, mana: 500 }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Boss {
+    pub hp: usize,
+    pub damage: usize
+}
+
+/// How a fight goes: the spells there are, the player's stats at the start, and whether it's on
+/// hard mode, where the player loses a hit point at the start of each of their turns.
+#[derive(Debug, Clone, Default)]
+pub struct Rules {
+    pub spellbook: Spellbook,
+    pub player: Player,
+    pub hard_mode: bool
+}
+
+impl Rules {
+    /// The puzzle's rules.
+    pub fn standard(hard_mode: bool) -> Rules {
+        Rules { hard_mode, ..Rules::default() }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Stats {
+    player_hp: usize,
+    player_mana: usize,
+    boss_hp: usize,
+    boss_damage: usize,
+    /// The turns left of each spell's effect, by its place in the spellbook.
+    timers: [u8; MAX_SPELLS]
+}
+
+impl Stats {
+    fn new(player: &Player, boss: &Boss) -> Stats {
+        Stats {
+            player_hp: player.hp,
+            player_mana: player.mana,
+            boss_hp: boss.hp,
+            boss_damage: boss.damage,
+            timers: [0; MAX_SPELLS]
+        }
+    }
+
+    /// Ticks every effect that's going, as happens at the start of each turn.  Gives the armor
+    /// they lend the player.
+    fn apply_effects(&mut self, spellbook: &Spellbook) -> usize {
+        let mut armor = 0;
+        for (i, spell) in spellbook.spells().iter().enumerate() {
+            if let (Some(effect), 1..) = (spell.effect, self.timers[i]) {
+                armor += effect.tick(self);
+                self.timers[i] -= 1;
+            }
+        }
+        armor
+    }
+
+    /// The player's turn up to casting: on hard mode they lose a hit point, then the effects tick.
+    fn begin_player_turn(mut self, rules: &Rules) -> GameState {
+        if rules.hard_mode {
+            if self.player_hp == 1 {
+                return BossWins;
+            }
+            self.player_hp -= 1;
+        }
+
+        self.apply_effects(&rules.spellbook);
+        self.check()
+    }
+
+    /// The player casts the `i`th spell, or `None` if they can't afford it or its effect is still
+    /// going.
+    fn cast(mut self, i: usize, spellbook: &Spellbook) -> Option<GameState> {
+        let spell = &spellbook.spells()[i];
+        if self.player_mana < spell.cost || self.timers[i] > 0 {
+            return None;
+        }
+
+        self.player_mana -= spell.cost;
+        self.boss_hp = self.boss_hp.saturating_sub(spell.damage);
+        self.player_hp += spell.heal;
+        if let Some(effect) = spell.effect {
+            self.timers[i] = effect.turns;
+        }
+        Some(self.check())
+    }
+
+    /// The boss's turn: the effects tick, then the boss attacks, always doing at least 1 damage.
+    fn boss_turn(mut self, spellbook: &Spellbook) -> GameState {
+        let armor = self.apply_effects(spellbook);
+        if self.boss_hp == 0 {
+            return PlayerWins;
+        }
+
+        let damage = self.boss_damage.saturating_sub(armor).max(1);
+        if self.player_hp <= damage {
+            return BossWins;
+        }
+        self.player_hp -= damage;
+        Live(self)
+    }
+
+    fn check(self) -> GameState {
+        if self.boss_hp == 0 { PlayerWins } else { Live(self) }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum GameState {
+    Live(Stats),
+    PlayerWins,
+    BossWins
+}
+use GameState::*;
+
+impl GameState {
+    /// The state after a round where the player tries to cast the `i`th spell, and whether they
+    /// did: the effects can end the fight first.  `None` if the spell can't be cast.
+    fn next_state(&self, i: usize, rules: &Rules) -> Option<(GameState, bool)> {
+        let Live(stats) = *self else { return None };
+
+        let turn_begun = stats.begin_player_turn(rules);
+        let Live(stats) = turn_begun else { return Some((turn_begun, false)) };
+
+        let cast = stats.cast(i, &rules.spellbook)?;
+        let Live(stats) = cast else { return Some((cast, true)) };
+
+        Some((stats.boss_turn(&rules.spellbook), true))
+    }
+}
+
+/// The fight as a search for the cheapest win, each spell cast costing its mana.
+struct Fight<'a> {
+    start: GameState,
+    rules: &'a Rules
+}
+
+impl Problem for Fight<'_> {
+    type State = GameState;
+    type Cost = usize;
+
+    fn start(&self) -> GameState {
+        self.start
+    }
+
+    fn successors(&self, state: &GameState) -> Vec<(GameState, usize)> {
+        self.rules.spellbook.spells().iter().enumerate()
+            .filter_map(|(i, spell)| {
+                let (next, cast) = state.next_state(i, self.rules)?;
+                Some((next, if cast { spell.cost } else { 0 }))
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, state: &GameState) -> bool {
+        matches!(state, PlayerWins)
+    }
+}
+
+mod parse {
+    use nom::branch::alt;
+    use nom::bytes::complete::{is_not, tag};
+    use nom::character::complete::newline;
+    use nom::combinator::{map, opt};
+    use nom::error::context;
+    use nom::multi::separated_list1;
+    use nom::sequence::{preceded, separated_pair, terminated, tuple};
+    use crate::parse::{lines, unsigned, IResult};
+    use super::{Boss, Effect, Spell};
+
+    pub fn boss(input: &str) -> IResult<&str, Boss> {
+        map(
+            separated_pair(
+                context(
+                    "hit points",
+                    preceded(tag("Hit Points: "), unsigned)
+                ),
+                newline,
+                context(
+                    "damage",
+                    preceded(tag("Damage: "), unsigned)
+                )
+            ),
+            |(hp, damage)| Boss { hp, damage }
+        )(input)
+    }
+
+    /// What a spell does when cast, like `2 damage, heal 2`: the damage and the healing.
+    fn instant(input: &str) -> IResult<&str, (usize, usize)> {
+        map(
+            separated_list1(
+                tag(", "),
+                context(
+                    "instant effect",
+                    alt((
+                        map(terminated(unsigned, tag(" damage")), |damage| (damage, 0)),
+                        map(preceded(tag("heal "), unsigned), |heal| (0, heal))
+                    ))
+                )
+            ),
+            |parts| parts.into_iter().fold((0, 0), |(damage, heal), part| (damage + part.0, heal + part.1))
+        )(input)
+    }
+
+    /// An effect, like `6 turns of 3 damage`.
+    fn effect(input: &str) -> IResult<&str, Effect> {
+        map(
+            separated_pair(
+                unsigned,
+                tag(" turns of "),
+                separated_list1(
+                    tag(", "),
+                    context(
+                        "effect",
+                        alt((
+                            map(terminated(unsigned, tag(" damage")), |damage| Effect { damage, ..Effect::default() }),
+                            map(preceded(tag("heal "), unsigned), |heal| Effect { heal, ..Effect::default() }),
+                            map(terminated(unsigned, tag(" armor")), |armor| Effect { armor, ..Effect::default() }),
+                            map(terminated(unsigned, tag(" mana")), |mana| Effect { mana, ..Effect::default() })
+                        ))
+                    )
+                )
+            ),
+            |(turns, parts)| parts.into_iter().fold(Effect { turns, ..Effect::default() }, |effect, part| Effect {
+                turns,
+                damage: effect.damage + part.damage,
+                heal: effect.heal + part.heal,
+                armor: effect.armor + part.armor,
+                mana: effect.mana + part.mana
+            })
+        )(input)
+    }
+
+    fn spell(input: &str) -> IResult<&str, Spell> {
+        context(
+            "spell",
+            map(
+                tuple((
+                    terminated(is_not(":\r\n"), tag(": ")),
+                    context("cost", terminated(unsigned, tag(" mana"))),
+                    opt(preceded(tag("; "), instant)),
+                    opt(preceded(tag("; "), effect))
+                )),
+                |(name, cost, instant, effect)| {
+                    let (damage, heal) = instant.unwrap_or((0, 0));
+                    Spell { name: name.to_owned(), cost, damage, heal, effect }
+                }
+            )
+        )(input)
+    }
+
+    pub fn spellbook(input: &str) -> IResult<&str, Vec<Spell>> {
+        lines(spell)(input)
+    }
+}
+
+/// A fight played out: the spell cast each round, if the effects didn't end the fight first, and
+/// how things stood after it.
+pub struct Battle {
+    spellbook: Spellbook,
+    start: GameState,
+    rounds: Vec<(Option<usize>, GameState)>,
+    mana_spent: usize
+}
+
+impl Battle {
+    /// The names of the spells cast, in order.
+    pub fn spells(&self) -> impl Iterator<Item = &str> + '_ {
+        self.rounds.iter().filter_map(|&(i, _)| Some(self.spellbook.spells()[i?].name.as_str()))
+    }
+
+    pub fn mana_spent(&self) -> usize {
+        self.mana_spent
+    }
+
+    fn write_round(&self, f: &mut Formatter<'_>, round: usize, spell: &str, state: &GameState, mana_spent: usize) -> std::fmt::Result {
+        let width = self.spell_width();
+        match state {
+            Live(stats) => {
+                write!(
+                    f,
+                    "{round:>5}  {spell:<width$}  {:>9}  {:>4}  {:>5}  {:>7}",
+                    stats.player_hp, stats.player_mana, mana_spent, stats.boss_hp
+                )?;
+                for (timer, spell) in stats.timers.iter().zip(self.spellbook.spells()) {
+                    if spell.effect.is_some() {
+                        write!(f, "  {timer:>0$}", spell.name.len())?;
+                    }
+                }
+                writeln!(f)
+            },
+            PlayerWins => writeln!(f, "{round:>5}  {spell:<width$}  player wins, {mana_spent} mana spent"),
+            BossWins => writeln!(f, "{round:>5}  {spell:<width$}  boss wins, {mana_spent} mana spent")
+        }
+    }
+
+    fn spell_width(&self) -> usize {
+        self.spellbook.spells().iter().map(|spell| spell.name.len()).max().unwrap_or(0).max("Spell".len())
+    }
+}
+
+impl Display for Battle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Round  {:<1$}  Player HP  Mana  Spent  Boss HP", "Spell", self.spell_width())?;
+        for spell in self.spellbook.spells().iter().filter(|spell| spell.effect.is_some()) {
+            write!(f, "  {}", spell.name)?;
+        }
+        writeln!(f)?;
+
+        self.write_round(f, 0, "", &self.start, 0)?;
+        let mut mana_spent = 0;
+        for (round, &(i, state)) in self.rounds.iter().enumerate() {
+            let spell = i.map(|i| &self.spellbook.spells()[i]);
+            mana_spent += spell.map_or(0, |spell| spell.cost);
+            self.write_round(f, round + 1, spell.map_or("", |spell| &spell.name), &state, mana_spent)?;
+        }
+        Ok(())
+    }
+}
+
+/// The cheapest win from `initial_state`, if there is one.  The mana spent so far isn't part of
+/// the state, so however a position was reached it's only searched from once, and the search
+/// stops at the first win it comes to.
+fn min_mana_to_win(initial_state: GameState, rules: &Rules) -> Result<Option<Battle>, AocError> {
+    let fight = Fight { start: initial_state, rules };
+    let Some(found) = dijkstra(&fight)? else { return Ok(None) };
+
+    // The path has the state after each round, but not the spell that led to it.  Only the end of
+    // the fight can follow from one state by more than one spell, and the search took the cheapest
+    // of those, which is the first that fits as the spells are in order of cost.
+    let rounds = found.path.windows(2)
+        .map(|pair| {
+            let (i, cast) = (0..rules.spellbook.spells().len())
+                .find_map(|i| match pair[0].next_state(i, rules) {
+                    Some((next, cast)) if next == pair[1] => Some((i, cast)),
+                    _ => None
+                })
+                .expect("each round follows from the last by some spell");
+            (cast.then_some(i), pair[1])
+        })
+        .collect();
+
+    Ok(Some(Battle { spellbook: rules.spellbook.clone(), start: initial_state, rounds, mana_spent: found.cost }))
+}
+
+/// The cheapest win against `boss`, if there is one.
+pub fn cheapest_win(boss: &Boss, rules: &Rules) -> Result<Option<Battle>, AocError> {
+    min_mana_to_win(Live(Stats::new(&rules.player, boss)), rules)
+}
+
+/// A fight played a round at a time, for trying things out by hand.
+pub struct Game {
+    rules: Rules,
+    state: GameState,
+    mana_spent: usize,
+    /// The state and mana spent before each round so far, for undoing it.
+    history: Vec<(GameState, usize)>
+}
+
+impl Game {
+    pub fn new(boss: &Boss, rules: Rules) -> Game {
+        let state = Live(Stats::new(&rules.player, boss));
+        Game { rules, state, mana_spent: 0, history: Vec::new() }
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// Plays a round where the player casts the `i`th spell, or says why they can't.
+    pub fn play(&mut self, i: usize) -> Result<(), String> {
+        let Live(stats) = self.state else { return Err("the fight is over".to_owned()) };
+        let spell = self.rules.spellbook.spells().get(i).ok_or_else(|| format!("no spell {i}"))?;
+
+        let Some((next, cast)) = self.state.next_state(i, &self.rules) else {
+            // Only the cast can have failed, after the start of the turn.
+            let Live(begun) = stats.begin_player_turn(&self.rules) else { unreachable!() };
+            return Err(if begun.timers[i] > 0 {
+                format!("{} is still going, with {} left", spell.name, turns(begun.timers[i]))
+            }
+            else {
+                format!("{} costs {} mana, but there's only {}", spell.name, spell.cost, begun.player_mana)
+            });
+        };
+
+        self.history.push((self.state, self.mana_spent));
+        self.state = next;
+        if cast {
+            self.mana_spent += spell.cost;
+        }
+        Ok(())
+    }
+
+    /// Takes back the last round, if there was one.
+    pub fn undo(&mut self) -> bool {
+        let Some((state, mana_spent)) = self.history.pop() else { return false };
+        self.state = state;
+        self.mana_spent = mana_spent;
+        true
+    }
+
+    /// `Some(true)` once the player has won, `Some(false)` once the boss has.
+    pub fn winner(&self) -> Option<bool> {
+        match self.state {
+            Live(_) => None,
+            PlayerWins => Some(true),
+            BossWins => Some(false)
+        }
+    }
+
+    pub fn mana_spent(&self) -> usize {
+        self.mana_spent
+    }
+
+    /// The cheapest way to win from here, if there is one.
+    pub fn best_continuation(&self) -> Result<Option<Battle>, AocError> {
+        min_mana_to_win(self.state, &self.rules)
+    }
+}
+
+fn turns(n: u8) -> String {
+    if n == 1 { "1 turn".to_owned() } else { format!("{n} turns") }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.state {
+            Live(stats) => {
+                writeln!(f, "Player: {} hit points, {} mana, {} spent", stats.player_hp, stats.player_mana, self.mana_spent)?;
+                writeln!(f, "Boss: {} hit points", stats.boss_hp)?;
+                let effects = self.rules.spellbook.spells().iter().zip(stats.timers)
+                    .filter(|&(_, timer)| timer > 0)
+                    .map(|(spell, timer)| format!("{} ({} left)", spell.name, turns(timer)))
+                    .join(", ");
+                writeln!(f, "Effects: {}", if effects.is_empty() { "none" } else { &effects })
+            },
+            PlayerWins => writeln!(f, "The player wins, having spent {} mana", self.mana_spent),
+            BossWins => writeln!(f, "The boss wins")
+        }
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Boss;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse::boss(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        cheapest_win(input, &Rules::standard(false))?
+            .map(|battle| battle.mana_spent().into())
+            .ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        cheapest_win(input, &Rules::standard(true))?
+            .map(|battle| battle.mana_spent().into())
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> Rules {
+        Rules { player: Player { hp: 10, mana: 250 }, ..Rules::standard(false) }
+    }
+
+    fn boss(hp: usize) -> Boss {
+        Boss { hp, damage: 8 }
+    }
+
+    #[test]
+    fn test_min_mana_to_win() -> Result<(), AocError> {
+        let battle = cheapest_win(&boss(13), &rules())?.unwrap();
+        assert_eq!(battle.mana_spent(), 226);
+        assert_eq!(battle.spells().collect::<Vec<_>>(), ["Poison", "Magic Missile"]);
+
+        let battle = cheapest_win(&boss(14), &rules())?.unwrap();
+        assert_eq!(battle.mana_spent(), 641);
+        assert_eq!(battle.spells().collect::<Vec<_>>(), ["Recharge", "Shield", "Drain", "Poison", "Magic Missile"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_battle_log() -> Result<(), AocError> {
+        assert_eq!(
+            cheapest_win(&boss(13), &rules())?.unwrap().to_string(),
+            "\
+Round  Spell          Player HP  Mana  Spent  Boss HP  Shield  Poison  Recharge
+    0                        10   250      0       13       0       0         0
+    1  Poison                 2    77    173       10       0       5         0
+    2  Magic Missile  player wins, 226 mana spent
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_spellbook() -> Result<(), AocError> {
+        let book = Spellbook::parse("Zap: 10 mana; 3 damage\nWard: 5 mana; 2 turns of 1 armor, 4 mana")?;
+        assert_eq!(book.spells()[0], Spell {
+            name: "Ward".to_owned(),
+            cost: 5,
+            damage: 0,
+            heal: 0,
+            effect: Some(Effect { turns: 2, damage: 0, heal: 0, armor: 1, mana: 4 })
+        });
+        assert_eq!(book.spells()[1].damage, 3);
+
+        assert!(Spellbook::parse("Zap: 10 mana; 1 turns").is_err());
+        assert!(Spellbook::parse("Zap: 10 mana; 0 turns of 1 damage").is_err());
+        assert!(Spellbook::parse("Zap: 10 mana\nZap: 20 mana").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_rules() -> Result<(), AocError> {
+        // Only a 3 damage spell, so four casts to beat a boss with 10 hit points.
+        let rules = Rules {
+            spellbook: Spellbook::parse("Zap: 10 mana; 3 damage")?,
+            player: Player { hp: 10, mana: 40 },
+            hard_mode: false
+        };
+        let battle = cheapest_win(&Boss { hp: 10, damage: 3 }, &rules)?.unwrap();
+        assert_eq!(battle.spells().count(), 4);
+        assert_eq!(battle.mana_spent(), 40);
+
+        // Hitting for 4, the boss wins in three rounds.
+        assert_eq!(cheapest_win(&Boss { hp: 10, damage: 4 }, &rules)?.map(|battle| battle.mana_spent()), None);
+        Ok(())
+    }
+
+    // Places of the standard spells in the spellbook.
+    const MAGIC_MISSILE: usize = 0;
+    const DRAIN: usize = 1;
+    const SHIELD: usize = 2;
+    const POISON: usize = 3;
+    const RECHARGE: usize = 4;
+
+    fn stats(player_hp: usize, player_mana: usize, boss_hp: usize, timers: &[(usize, u8)]) -> Stats {
+        let mut stats = Stats { player_hp, player_mana, boss_hp, boss_damage: 8, timers: [0; MAX_SPELLS] };
+        for &(i, turns) in timers {
+            stats.timers[i] = turns;
+        }
+        stats
+    }
+
+    #[test]
+    fn test_begin_player_turn() {
+        let rules = Rules::standard(false);
+        assert_eq!(
+            stats(10, 0, 10, &[(POISON, 2), (RECHARGE, 1)]).begin_player_turn(&rules),
+            Live(stats(10, 101, 7, &[(POISON, 1)]))
+        );
+        assert_eq!(stats(10, 0, 3, &[(POISON, 1)]).begin_player_turn(&rules), PlayerWins);
+
+        let hard = Rules::standard(true);
+        assert_eq!(stats(10, 0, 10, &[]).begin_player_turn(&hard), Live(stats(9, 0, 10, &[])));
+        assert_eq!(stats(1, 0, 10, &[]).begin_player_turn(&hard), BossWins);
+    }
+
+    #[test]
+    fn test_cast() {
+        let book = Spellbook::default();
+        assert_eq!(stats(10, 52, 10, &[]).cast(MAGIC_MISSILE, &book), None);
+        assert_eq!(stats(10, 100, 10, &[]).cast(DRAIN, &book), Some(Live(stats(12, 27, 8, &[]))));
+        assert_eq!(stats(10, 200, 10, &[]).cast(SHIELD, &book), Some(Live(stats(10, 87, 10, &[(SHIELD, 6)]))));
+        assert_eq!(stats(10, 200, 4, &[]).cast(MAGIC_MISSILE, &book), Some(PlayerWins));
+
+        // An effect can't be cast again while it's going, but can on the turn it ends.
+        let poisoned = stats(10, 200, 10, &[(POISON, 1)]);
+        assert_eq!(poisoned.cast(POISON, &book), None);
+        let Live(ended) = poisoned.begin_player_turn(&Rules::standard(false)) else { panic!("boss died") };
+        assert_eq!(ended.cast(POISON, &book), Some(Live(stats(10, 27, 7, &[(POISON, 6)]))));
+    }
+
+    #[test]
+    fn test_boss_turn() {
+        let book = Spellbook::default();
+        assert_eq!(stats(10, 0, 10, &[]).boss_turn(&book), Live(stats(2, 0, 10, &[])));
+        assert_eq!(stats(10, 0, 10, &[(SHIELD, 2)]).boss_turn(&book), Live(stats(9, 0, 10, &[(SHIELD, 1)])));
+        assert_eq!(stats(8, 0, 10, &[]).boss_turn(&book), BossWins);
+        assert_eq!(stats(8, 0, 3, &[(POISON, 3)]).boss_turn(&book), PlayerWins);
+
+        let weak = Stats { boss_damage: 5, ..stats(10, 0, 10, &[(SHIELD, 2)]) };
+        assert_eq!(weak.boss_turn(&book), Live(Stats { boss_damage: 5, ..stats(9, 0, 10, &[(SHIELD, 1)]) }));
+    }
+
+    #[test]
+    fn test_heal_over_time() -> Result<(), AocError> {
+        let book = Spellbook::parse("Regenerate: 100 mana; 3 turns of heal 2")?;
+        let Some(Live(cast)) = stats(10, 100, 10, &[]).cast(0, &book) else { panic!("couldn't cast") };
+        assert_eq!(cast.boss_turn(&book), Live(stats(4, 0, 10, &[(0, 2)])));
+        Ok(())
+    }
+
+    #[test]
+    fn test_game() -> Result<(), AocError> {
+        let mut game = Game::new(&boss(13), rules());
+        assert_eq!(game.to_string(), "Player: 10 hit points, 250 mana, 0 spent\nBoss: 13 hit points\nEffects: none\n");
+
+        game.play(POISON).unwrap();
+        assert_eq!(game.to_string(), "Player: 2 hit points, 77 mana, 173 spent\nBoss: 10 hit points\nEffects: Poison (5 turns left)\n");
+        assert_eq!(game.play(RECHARGE), Err("Recharge costs 229 mana, but there's only 77".to_owned()));
+        assert_eq!(game.play(POISON), Err("Poison is still going, with 4 turns left".to_owned()));
+        assert_eq!(game.play(5), Err("no spell 5".to_owned()));
+        assert_eq!(game.best_continuation()?.map(|battle| battle.mana_spent()), Some(53));
+
+        game.play(DRAIN).unwrap();
+        assert_eq!(game.winner(), Some(false));
+        assert!(game.play(DRAIN).is_err());
+
+        assert!(game.undo());
+        game.play(MAGIC_MISSILE).unwrap();
+        assert_eq!(game.winner(), Some(true));
+        assert_eq!(game.mana_spent(), 226);
+        Ok(())
+    }
+
+    #[test]
+    fn test_win_by_effect() -> Result<(), AocError> {
+        // Poison finishes the boss off at the start of the player's next turn, before they cast
+        // anything more, so the win costs only the poison.
+        let rules = Rules {
+            spellbook: Spellbook::parse("Poison: 173 mana; 6 turns of 3 damage")?,
+            player: Player { hp: 20, mana: 173 },
+            hard_mode: false
+        };
+        let battle = cheapest_win(&boss(6), &rules)?.unwrap();
+        assert_eq!(battle.mana_spent(), 173);
+        assert_eq!(battle.spells().collect::<Vec<_>>(), ["Poison"]);
+        Ok(())
+    }
+}
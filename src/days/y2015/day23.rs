@@ -0,0 +1,183 @@
+use std::ops::{Index, IndexMut};
+use crate::cancel;
+use crate::error::AocError;
+use crate::parse::finish;
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone)]
+pub enum Register { A, B }
+
+#[derive(Copy, Clone)]
+pub enum Offset { Forward(usize), Backward(usize) }
+use Offset::*;
+
+#[derive(Copy, Clone)]
+pub enum Instruction {
+    Hlf(Register),
+    Tpl(Register),
+    Inc(Register),
+    Jmp(Offset),
+    Jie(Register, Offset),
+    Jio(Register, Offset)
+}
+use Instruction::*;
+
+
+struct State { a: usize, b: usize, cur: Option<usize> }
+
+impl Index<Register> for State {
+    type Output = usize;
+
+    fn index(&self, index: Register) -> &Self::Output {
+        match index {
+            Register::A => &self.a,
+            Register::B => &self.b
+        }
+    }
+}
+
+impl IndexMut<Register> for State {
+    fn index_mut(&mut self, index: Register) -> &mut Self::Output {
+        match index {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b
+        }
+    }
+}
+
+fn run(program: &[Instruction], (a, b, cur): (usize, usize, Option<usize>)) -> Result<usize, AocError> {
+    let mut state = State { a, b, cur };
+    let mut steps = 0;
+
+    loop {
+        cancel::tick(steps)?;
+        steps += 1;
+        match state.cur {
+            None => return Ok(state.b),
+            Some(i) => {
+                let instr = program[i];
+                let offset = match instr {
+                    Hlf(r) => { state[r] /= 2; Forward(1) },
+                    Tpl(r) => { state[r] *= 3; Forward(1) },
+                    Inc(r) => { state[r] += 1; Forward(1) },
+                    Jmp(o) => o,
+                    Jie(r, o) => { if state[r].is_multiple_of(2) { o } else { Forward(1) }},
+                    Jio(r, o) => { if state[r] == 1 { o } else { Forward(1) }}
+                };
+
+                state.cur = match (i, offset) {
+                    (i, Forward(d)) if i + d >= program.len() => None,
+                    (i, Forward(d)) => Some(i + d),
+                    (i, Backward(d)) if i < d => None,
+                    (i, Backward(d)) => Some(i - d)
+                }
+            }
+        }
+    }
+}
+
+mod parse {
+    use nom::branch::alt;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::char;
+    use nom::combinator::{cut, map, value};
+    use nom::error::context;
+    use nom::sequence::{pair, preceded, terminated};
+    use crate::parse::{lines, unsigned, IResult};
+    use super::{Instruction, Offset, Register};
+    use super::Instruction::*;
+    use super::Offset::*;
+
+    fn register(input: &str) -> IResult<&str, Register> {
+        context(
+            "register",
+            alt((
+                value(Register::A, tag("a")),
+                value(Register::B, tag("b"))
+            ))
+        )(input)
+    }
+
+    fn offset(input: &str) -> IResult<&str, Offset> {
+        context(
+            "offset",
+            map(
+                pair(
+                    alt((
+                        value(true, char('+')),
+                        value(false, char('-'))
+                    )),
+                    unsigned
+                ),
+                |(is_pos, num)| { if is_pos { Forward(num) } else { Backward(num) } }
+            )
+        )(input)
+    }
+
+
+    fn instruction(input: &str) -> IResult<&str, Instruction> {
+        context(
+            "instruction",
+            alt((
+                map(preceded(tag("hlf "), cut(register)), Hlf),
+                map(preceded(tag("tpl "), cut(register)), Tpl),
+                map(preceded(tag("inc "), cut(register)), Inc),
+                map(preceded(tag("jmp "), cut(offset)), Jmp),
+                map(
+                    preceded(
+                        tag("jie "),
+                        cut(pair(terminated(register, tag(", ")), offset))
+                    ),
+                    |(r, o)| Jie(r, o)
+                ),
+                map(
+                    preceded(
+                        tag("jio "),
+                        cut(pair(terminated(register, tag(", ")), offset))
+                    ),
+                    |(r, o)| Jio(r, o)
+                )
+            ))
+        )(input)
+    }
+
+    pub fn instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+        lines(instruction)(input)
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, parse::instructions(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(run(input, (0, 0, Some(0)))?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(run(input, (1, 0, Some(0)))?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example from the puzzle, using register `b` since that's the one the answer is read
+    // from.
+    const TEST_INPUT: &str = "\
+inc b
+jio b, +2
+tpl b
+inc b";
+
+    crate::examples! {
+        Day23;
+        program: TEST_INPUT => part1 = 2, part2 = 2;
+    }
+}
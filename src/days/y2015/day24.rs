@@ -0,0 +1,215 @@
+use std::ops::Add;
+use itertools::Itertools;
+use crate::error::AocError;
+use crate::parse::{finish, lines, unsigned};
+use crate::search::{branch_and_bound, Cost, Objective, Problem};
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct BitSet {
+    bits: usize,
+    cap: usize
+}
+
+impl BitSet {
+    fn new(cap: usize) -> BitSet {
+        BitSet { bits: 0, cap }
+    }
+
+    fn insert(&mut self, x: usize) -> bool {
+        let result = self.bits & (1 << x) > 0;
+        self.bits |= 1 << x;
+        result
+    }
+
+    fn iter(&self) -> BitSetIter {
+        BitSetIter { bits: self.bits, i: 0 }
+    }
+
+    fn complement(&self) -> BitSet {
+        let mask = (1 << self.cap) - 1;
+        let negated = !self.bits;
+        let new_bits = negated & mask;
+        BitSet { bits: new_bits, cap: self.cap }
+    }
+}
+
+struct BitSetIter {
+    bits: usize,
+    i: usize
+}
+
+impl Iterator for BitSetIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.bits > 0 && self.bits & 1 == 0 {
+            self.bits >>= 1;
+            self.i += 1;
+        }
+
+        if self.bits == 0 {
+            None
+        }
+        else {
+            let result = Some(self.i);
+            self.bits ^= 1;
+            result
+        }
+    }
+}
+
+fn can_split_in_n(nums: &[usize], n: usize) -> bool {
+    if n == 1 {
+        return !nums.is_empty()
+    }
+
+    let s = nums.iter().sum::<usize>();
+    if s % n != 0 {
+        return false;
+    }
+
+    let target = s / n;
+
+    let mut stack = vec![(BitSet::new(nums.len()), 0, target)];
+
+    while let Some((elems, cur_i, cur_target)) = stack.pop() {
+        if cur_target == 0 {
+            let remaining = elems.complement().iter().collect_vec();
+            if can_split_in_n(&remaining, n - 1) {
+                return true
+            }
+            else {
+                continue
+            }
+        }
+
+        if cur_i == nums.len() {
+            continue;
+        }
+
+        stack.push((elems, cur_i + 1, cur_target));
+
+        if cur_target >= nums[cur_i] {
+            let mut new_elems = elems;
+            new_elems.insert(cur_i);
+            stack.push((new_elems, cur_i + 1, cur_target - nums[cur_i]));
+        }
+
+    }
+
+    false
+}
+
+/// How good a group of packages is for the passenger compartment: the fewer packages the better,
+/// then the lower their quantum entanglement.  Adding groups together gives the group of all their
+/// packages.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+struct Group { count: usize, product: u128 }
+
+impl Add for Group {
+    type Output = Group;
+
+    fn add(self, other: Group) -> Group {
+        Group { count: self.count + other.count, product: self.product * other.product }
+    }
+}
+
+impl Cost for Group {
+    const ZERO: Group = Group { count: 0, product: 1 };
+}
+
+/// A group for the passenger compartment picked in order of the packages: those picked, their
+/// total weight, and the next package that could be picked.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Picked { elems: BitSet, sum: usize, next: usize }
+
+/// The search for the best group for the passenger compartment that leaves the rest able to be
+/// split `n - 1` ways.
+struct Packing<'a> {
+    numbers: &'a [usize],
+    n: usize,
+    target: usize
+}
+
+impl Problem for Packing<'_> {
+    type State = Picked;
+    type Cost = Group;
+
+    fn start(&self) -> Picked {
+        Picked { elems: BitSet::new(self.numbers.len()), sum: 0, next: 0 }
+    }
+
+    fn successors(&self, picked: &Picked) -> Vec<(Picked, Group)> {
+        (picked.next..self.numbers.len())
+            .filter(|&i| picked.sum + self.numbers[i] <= self.target)
+            .map(|i| {
+                let mut elems = picked.elems;
+                elems.insert(i);
+                let next = Picked { elems, sum: picked.sum + self.numbers[i], next: i + 1 };
+                (next, Group { count: 1, product: self.numbers[i] as u128 })
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, picked: &Picked) -> bool {
+        if picked.sum != self.target {
+            return false;
+        }
+
+        let remaining = picked.elems.complement().iter().map(|i| self.numbers[i]).collect_vec();
+        can_split_in_n(&remaining, self.n - 1)
+    }
+}
+
+fn solve(numbers: &[usize], n: usize) -> Result<u128, AocError> {
+    if numbers.len() >= usize::BITS as usize {
+        return Err(AocError::invalid_input(format!("too many packages: {}", numbers.len())));
+    }
+
+    let total = numbers.iter().sum::<usize>();
+
+    if total % n != 0 {
+        return Err(AocError::invalid_input(format!("total weight {total} can't be split {n} ways")));
+    }
+
+    let packing = Packing { numbers, n, target: total / n };
+    branch_and_bound(&packing, Objective::Minimise)?
+        .map(|found| found.cost.product)
+        .ok_or(AocError::NoSolution)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(input, lines(unsigned)(input))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solve(input, 3)?.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(solve(input, 4)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let numbers = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+        assert_eq!(solve(&numbers, 3), Ok(99));
+    }
+
+    #[test]
+    fn test_part2() {
+        let numbers = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+        assert_eq!(solve(&numbers, 4), Ok(44));
+    }
+}
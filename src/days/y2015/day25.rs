@@ -0,0 +1,80 @@
+use std::iter::once;
+use crate::cancel;
+use crate::error::AocError;
+use crate::parse::integers;
+use crate::solution::{Answer, Solution};
+
+struct CodePosIter {
+    i: usize,
+    j: usize
+}
+
+impl CodePosIter {
+    fn new() -> CodePosIter {
+        CodePosIter { i: 0, j: 0 }
+    }
+}
+
+impl Iterator for CodePosIter {
+    type Item = (usize, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i == 0 {
+            self.i = self.i + self.j + 1;
+            self.j = 0;
+        }
+        else {
+            self.i -= 1;
+            self.j += 1;
+        }
+
+        Some((self.i, self.j))
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = (usize, usize);
+
+    const PARTS: usize = 1;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let [r, c] = integers::<usize>(input)?[..] else {
+            return Err(AocError::invalid_input("expected exactly two numbers, a row and a column"));
+        };
+
+        if r == 0 || c == 0 {
+            return Err(AocError::invalid_input("rows and columns are numbered from 1"));
+        }
+
+        Ok((r, c))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let &(r, c) = input;
+        let target = (r - 1, c - 1);
+        let mut cur = 20151125u128;
+        for (i, coord) in once((0, 0)).chain(CodePosIter::new()).enumerate() {
+            cancel::tick(i)?;
+            if coord == target {
+                return Ok(cur.into());
+            }
+            cur = (cur * 252533) % 33554393;
+        }
+
+        Err(AocError::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::examples! {
+        Day25;
+        first: "row 1, column 1." => part1 = 20151125;
+        second_row: "row 2, column 1." => part1 = 31916031;
+        fourth_row: "row 4, column 2." => part1 = 32451966;
+        corner: "row 6, column 6." => part1 = 27995004;
+    }
+}
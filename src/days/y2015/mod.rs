@@ -0,0 +1,56 @@
+use super::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Every solved day of 2015, in order.
+pub static DAYS: &[Day] = &[
+    day!(2015, 1, day01::Day01),
+    day!(2015, 2, day02::Day02),
+    day!(2015, 3, day03::Day03),
+    day!(2015, 4, day04::Day04),
+    day!(2015, 5, day05::Day05),
+    day!(2015, 6, day06::Day06),
+    day!(2015, 7, day07::Day07),
+    day!(2015, 8, day08::Day08),
+    day!(2015, 9, day09::Day09),
+    day!(2015, 10, day10::Day10),
+    day!(2015, 11, day11::Day11),
+    day!(2015, 12, day12::Day12),
+    day!(2015, 13, day13::Day13),
+    day!(2015, 14, day14::Day14),
+    day!(2015, 15, day15::Day15),
+    day!(2015, 16, day16::Day16),
+    day!(2015, 17, day17::Day17),
+    day!(2015, 18, day18::Day18),
+    day!(2015, 19, day19::Day19),
+    day!(2015, 20, day20::Day20),
+    day!(2015, 21, day21::Day21),
+    day!(2015, 22, day22::Day22),
+    day!(2015, 23, day23::Day23),
+    day!(2015, 24, day24::Day24),
+    day!(2015, 25, day25::Day25),
+];
@@ -0,0 +1,53 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input doesn't match the puzzle's format.  Lines and columns are 1-based.
+    Parse { line: usize, column: usize, message: String },
+    /// The input is well-formed, but the solver couldn't find an answer for it.
+    NoSolution,
+    /// The input parses, but breaks an assumption the puzzle makes about its shape.
+    InvalidInput(String),
+    /// A part was asked for that the puzzle doesn't have.
+    NoSuchPart(usize),
+    /// The solver gave up after running for the given time limit (see [`crate::cancel`]).
+    TimedOut(Duration)
+}
+
+impl AocError {
+    /// A parse error at the start of `rest`, which must be a suffix of `input`.
+    pub fn parse(input: &str, rest: &str, message: impl Into<String>) -> AocError {
+        AocError::parse_at(input, input.len() - rest.len(), message)
+    }
+
+    /// A parse error at byte `offset` of `input`.
+    pub fn parse_at(input: &str, offset: usize, message: impl Into<String>) -> AocError {
+        let consumed = &input[..offset];
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed[line_start..].chars().count() + 1;
+
+        AocError::Parse { line, column, message: message.into() }
+    }
+
+    pub fn invalid_input(message: impl Into<String>) -> AocError {
+        AocError::InvalidInput(message.into())
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Parse { line, column, message } => {
+                write!(f, "parse error at line {line}, column {column}: {message}")
+            },
+            AocError::NoSolution => write!(f, "no solution found"),
+            AocError::InvalidInput(message) => write!(f, "invalid input: {message}"),
+            AocError::NoSuchPart(part) => write!(f, "there is no part {part}"),
+            AocError::TimedOut(limit) => write!(f, "timed out after {} ms", limit.as_millis())
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
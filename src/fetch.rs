@@ -0,0 +1,311 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::time::Duration;
+use std::{env, fs, io};
+use crate::answers::Rejection;
+use crate::solution::Answer;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable overriding [`DEFAULT_BASE_URL`], e.g. to point at a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the value of the `session` cookie to log in with.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    /// The server couldn't be reached, or the response couldn't be read.
+    Request { url: String, message: String },
+    /// The server answered, but not with success; `body` is its explanation.
+    Status { url: String, status: u16, body: String },
+    /// The server answered a submission with a page that doesn't say what it made of it.
+    Unrecognised { url: String },
+    Write { path: String, error: io::Error }
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::NoSession => write!(f, "no session cookie: set {SESSION_VAR}"),
+            FetchError::Request { url, message } => write!(f, "could not fetch {url}: {message}"),
+            FetchError::Status { url, status, body } => write!(f, "could not fetch {url}: HTTP {status}: {body}"),
+            FetchError::Unrecognised { url } => write!(f, "could not make sense of the response from {url}"),
+            FetchError::Write { path, error } => write!(f, "could not write {path}: {error}")
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// A logged in connection to the puzzle server.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Client { base_url: base_url.trim_end_matches('/').to_owned(), session: session.to_owned(), agent }
+    }
+
+    /// A client for `$AOC_BASE_URL` (or the real server), logged in with `$AOC_SESSION`.
+    pub fn from_env() -> Result<Client, FetchError> {
+        let session = env::var(SESSION_VAR).map_err(|_| FetchError::NoSession)?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        Ok(Client::new(&base_url, &session))
+    }
+
+    /// Downloads the logged in user's input for a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let request = self.agent.get(&url).header("Cookie", &format!("session={}", self.session));
+        read_response(&url, request.call())
+    }
+
+    /// Submits `answer` for a part of a day.
+    pub fn submit(&self, year: u16, day: u8, part: usize, answer: &Answer) -> Result<Verdict, FetchError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let request = self.agent.post(&url).header("Cookie", &format!("session={}", self.session));
+        let page = read_response(&url, request.send_form([("level", part.to_string()), ("answer", answer.to_string())]))?;
+        parse_verdict(&page).ok_or(FetchError::Unrecognised { url })
+    }
+}
+
+/// What the server made of a submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Rejected(Rejection),
+    /// An answer was submitted too recently; try again after this long, if the server said.
+    Wait(Option<Duration>),
+    /// The part has already been solved, or isn't unlocked yet.
+    WrongLevel
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Rejected(rejection) => write!(f, "{rejection}"),
+            Verdict::Wait(Some(wait)) => write!(f, "answered too recently, wait {}s", wait.as_secs()),
+            Verdict::Wait(None) => write!(f, "answered too recently"),
+            Verdict::WrongLevel => write!(f, "part already solved or not unlocked yet")
+        }
+    }
+}
+
+/// Reads the verdict from the page the server responds to a submission with.
+fn parse_verdict(page: &str) -> Option<Verdict> {
+    if page.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    }
+    else if page.contains("That's not the right answer") {
+        let rejection = if page.contains("your answer is too high") {
+            Rejection::TooHigh
+        }
+        else if page.contains("your answer is too low") {
+            Rejection::TooLow
+        }
+        else {
+            Rejection::Wrong
+        };
+        Some(Verdict::Rejected(rejection))
+    }
+    else if page.contains("You gave an answer too recently") {
+        let wait = page.split_once("You have ").and_then(|(_, rest)| parse::wait(rest).ok());
+        Some(Verdict::Wait(wait.map(|(_, wait)| wait)))
+    }
+    else if page.contains("You don't seem to be solving the right level") {
+        Some(Verdict::WrongLevel)
+    }
+    else {
+        None
+    }
+}
+
+fn read_response(url: &str, response: Result<ureq::http::Response<ureq::Body>, ureq::Error>) -> Result<String, FetchError> {
+    let request_error = |e: ureq::Error| FetchError::Request { url: url.to_owned(), message: e.to_string() };
+
+    let mut response = response.map_err(request_error)?;
+    let status = response.status().as_u16();
+    let body = response.body_mut().read_to_string().map_err(request_error)?;
+
+    if status != 200 {
+        return Err(FetchError::Status { url: url.to_owned(), status, body: body.trim().to_owned() });
+    }
+    Ok(body)
+}
+
+/// Downloads the input for a day to `path`, unless it's already there.  Returns whether it was
+/// downloaded.
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<bool, FetchError> {
+    if path.exists() {
+        return Ok(false);
+    }
+
+    let input = client.input(year, day)?;
+
+    // Write to a temporary file first, so an interrupted write never leaves a partial input behind
+    // that would be taken for the real thing.
+    let partial = path.with_extension("part");
+    let write_error = |error| FetchError::Write { path: path.display().to_string(), error };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_error)?;
+    }
+    fs::write(&partial, input).map_err(write_error)?;
+    fs::rename(&partial, path).map_err(write_error)?;
+
+    Ok(true)
+}
+
+mod parse {
+    use std::time::Duration;
+    use nom::character::complete::{char, space0};
+    use nom::combinator::{map, opt};
+    use nom::sequence::{pair, terminated};
+    use crate::parse::{unsigned, IResult};
+
+    /// A wait like `1m 30s` or `45s`.
+    pub fn wait(input: &str) -> IResult<&str, Duration> {
+        map(
+            pair(
+                opt(terminated(unsigned::<u64>, pair(char('m'), space0))),
+                terminated(unsigned::<u64>, char('s'))
+            ),
+            |(minutes, seconds)| Duration::from_secs(minutes.unwrap_or(0) * 60 + seconds)
+        )(input)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::thread::{self, JoinHandle};
+    use super::*;
+
+    /// A stand-in for the puzzle server, answering one request with each of `responses` (a status
+    /// and body) in turn.  Gives its base URL, and a handle that returns the requests it received,
+    /// each as its request line, headers and body.
+    pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("{}-{name}-{}", env!("CARGO_PKG_NAME"), std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve(vec![(200, "1x2x3\n")]);
+        let client = Client::new(&base_url, "abc123");
+        let path = scratch_dir("fetch").join("day02.txt");
+
+        assert!(fetch_input(&client, 2015, 2, &path).unwrap());
+        // Already cached, so the server (which only answers once) isn't asked again.
+        assert!(!fetch_input(&client, 2015, 2, &path).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1x2x3\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2015/day/2/input "));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let (base_url, server) = serve(vec![(400, "Please log in.\n")]);
+        let client = Client::new(&base_url, "expired");
+        let path = scratch_dir("fetch-error").join("day01.txt");
+
+        let err = fetch_input(&client, 2015, 1, &path).unwrap_err();
+        assert!(matches!(err, FetchError::Status { status: 400, ref body, .. } if body == "Please log in."));
+        assert!(!path.exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |text| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+
+        assert_eq!(parse_verdict(&page("That's the right answer!  You are one gold star closer.")), Some(Verdict::Correct));
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer; your answer is too low.  Please wait one minute.")),
+            Some(Verdict::Rejected(Rejection::TooLow))
+        );
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer.  If you're stuck, make sure you're using the full input.")),
+            Some(Verdict::Rejected(Rejection::Wrong))
+        );
+        assert_eq!(
+            parse_verdict(&page("You gave an answer too recently.  You have 1m 5s left to wait.")),
+            Some(Verdict::Wait(Some(Duration::from_secs(65))))
+        );
+        assert_eq!(parse_verdict(&page("You don't seem to be solving the right level.")), Some(Verdict::WrongLevel));
+        assert_eq!(parse_verdict(&page("Something else entirely")), None);
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = serve(vec![(200, "<article><p>That's not the right answer; your answer is too high.</p></article>")]);
+        let client = Client::new(&base_url, "abc123");
+
+        assert_eq!(client.submit(2015, 7, 2, &Answer::from(956)).unwrap(), Verdict::Rejected(Rejection::TooHigh));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2015/day/7/answer "));
+        assert!(requests[0].ends_with("level=2&answer=956"));
+    }
+}
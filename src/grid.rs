@@ -0,0 +1,226 @@
+use std::collections::hash_map::{self, HashMap};
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid, as row then column.  Signed, so that stepping off the edge of a [`Grid`]
+/// gives a position it doesn't contain rather than an overflow, and so a [`SparseGrid`] can
+/// extend any way.
+pub type Pos = (isize, isize);
+
+/// Steps to the positions above, left, below and right.
+pub const ORTHOGONAL: [Pos; 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+
+/// Steps to all eight surrounding positions, diagonals included.
+pub const SURROUNDING: [Pos; 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+pub fn step((i, j): Pos, (di, dj): Pos) -> Pos {
+    (i + di, j + dj)
+}
+
+/// A rectangular grid stored densely, row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Grid<T>
+    where T: Clone
+    {
+        Grid { rows, cols, cells: vec![fill; rows * cols] }
+    }
+
+    /// A grid with each cell set to `f` of its position.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        let cells = (0..rows * cols).map(|k| f(((k / cols) as isize, (k % cols) as isize))).collect();
+        Grid { rows, cols, cells }
+    }
+
+    /// A grid from its rows, or `None` unless there's at least one and they're all as wide.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let cols = rows.first()?.len();
+        if cols == 0 || rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+
+        Some(Grid { rows: rows.len(), cols, cells: rows.into_iter().flatten().collect() })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (i, j): Pos) -> bool {
+        (0..self.rows as isize).contains(&i) && (0..self.cols as isize).contains(&j)
+    }
+
+    fn offset(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos).then(|| pos.0 as usize * self.cols + pos.1 as usize)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|k| &self.cells[k])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(|k| &mut self.cells[k])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |k| ((k / cols) as isize, (k % cols) as isize))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The positions in the grid next to `pos`, with `steps` such as [`ORTHOGONAL`] or
+    /// [`SURROUNDING`].
+    pub fn neighbors<'a>(&'a self, pos: Pos, steps: &'a [Pos]) -> impl Iterator<Item = Pos> + 'a {
+        steps.iter().map(move |&d| step(pos, d)).filter(|&p| self.contains(p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { rows: self.rows, cols: self.cols, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// The grid as text, one line per row with a character per cell.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.cells.chunks(self.cols.max(1))
+            .map(|row| row.iter().map(&f).chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{pos:?} is outside a {}x{} grid", self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{pos:?} is outside a {rows}x{cols} grid"))
+    }
+}
+
+/// An unbounded grid that only stores the cells that have been set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid { cells: HashMap::new() }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// How many cells are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn entry(&mut self, pos: Pos) -> hash_map::Entry<'_, Pos, T> {
+        self.cells.entry(pos)
+    }
+
+    /// The set cells with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding every set cell.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        let mut positions = self.cells.keys();
+        let &first = positions.next()?;
+        Some(positions.fold((first, first), |((top, left), (bottom, right)), &(i, j)| {
+            ((top.min(i), left.min(j)), (bottom.max(i), right.max(j)))
+        }))
+    }
+
+    /// The rectangle within [`bounds`](Self::bounds) as text, with `f` choosing the character for
+    /// each cell, set or not.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        let Some(((top, left), (bottom, right))) = self.bounds() else { return String::new() };
+        (top..=bottom)
+            .map(|i| (left..=right).map(|j| f(self.get((i, j)))).chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+
+        grid[(0, 0)] = 7;
+        assert_eq!(grid.iter().next(), Some(((0, 0), &7)));
+        assert_eq!(grid.neighbors((0, 0), &ORTHOGONAL).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors((1, 1), &SURROUNDING).count(), 5);
+        assert_eq!(grid.map(|&n| if n % 2 == 0 { '#' } else { '.' }).render(|&c| c), ".#.\n#.#\n");
+
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+        assert_eq!(Grid::from_fn(2, 2, |(i, j)| i * 2 + j).values().sum::<isize>(), 6);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert((-1, 2), 'a');
+        grid.insert((1, 0), 'b');
+        *grid.entry((1, 0)).or_insert('x') = 'c';
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some(((-1, 0), (1, 2))));
+        assert_eq!(grid.render(|c| c.copied().unwrap_or('.')), "..a\n...\nc..\n");
+    }
+}
@@ -1,31 +1,218 @@
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs, io};
+
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod days;
+pub mod error;
+pub mod fetch;
+pub mod grid;
+pub mod parse;
+pub mod search;
+pub mod solution;
+
+/// Directory the solvers look in when no input path is given on the command line.
+pub const INPUT_DIR: &str = "input";
+
+/// Environment variable that overrides [`INPUT_DIR`] as the input root.
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+
+#[derive(Debug)]
+pub struct InputError {
+    source: String,
+    error: io::Error
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not read input from {}: {}", self.source, self.error)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Reads the puzzle input named by the first command line argument, as [`read_input_from`] does.
+pub fn read_input(year: u16, day: u8) -> Result<String, InputError> {
+    read_input_from(env::args().nth(1).as_deref(), year, day)
+}
+
+/// Reads the puzzle input named by `arg`: a file path, or `-` for stdin.  Without one, falls back
+/// to the day's input in the input root (see [`input_root`]), first downloading it there if it's
+/// missing and `$AOC_SESSION` is set.
+pub fn read_input_from(arg: Option<&str>, year: u16, day: u8) -> Result<String, InputError> {
+    match arg {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(|error| InputError { source: "stdin".to_owned(), error })
+        },
+        Some(arg) => read_file(PathBuf::from(arg)),
+        None => {
+            let path = Inputs::root(&input_root(None)).day_path(year, day);
+            if !path.exists() && env::var_os(fetch::SESSION_VAR).is_some() {
+                fetch::Client::from_env()
+                    .and_then(|client| fetch::fetch_input(&client, year, day, &path))
+                    .map_err(|e| InputError { source: path.display().to_string(), error: io::Error::other(e) })?;
+            }
+            read_file(path)
+        }
+    }
+}
+
+/// The input root: `dir` if given, otherwise `$AOC_INPUTS`, otherwise `input`.
+pub fn input_root(dir: Option<&str>) -> PathBuf {
+    match dir {
+        Some(dir) => PathBuf::from(dir),
+        None => env::var_os(INPUTS_VAR).map_or_else(|| PathBuf::from(INPUT_DIR), PathBuf::from)
+    }
+}
+
+/// A directory of inputs, a subdirectory per year holding `dayNN.txt` files along with the answers
+/// known for them in `answers.toml`.  That's either the input root itself, or a profile: a
+/// subdirectory of the root holding one person's inputs, e.g. `input/alice/2015/day07.txt`.
+/// Profiles can have any name that isn't a year.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inputs {
+    /// `None` for the input root itself.
+    pub profile: Option<String>,
+    pub dir: PathBuf
+}
+
+impl Inputs {
+    pub fn root(root: &Path) -> Inputs {
+        Inputs { profile: None, dir: root.to_owned() }
+    }
+
+    pub fn profile(root: &Path, name: &str) -> Inputs {
+        Inputs { profile: Some(name.to_owned()), dir: root.join(name) }
+    }
+
+    /// Every profile under `root`, in name order.  Directories named with just digits hold the
+    /// root's own inputs for a year, so aren't profiles.
+    pub fn profiles(root: &Path) -> Result<Vec<Inputs>, InputError> {
+        let error = |error| InputError { source: root.display().to_string(), error };
+
+        let mut names = Vec::new();
+        for entry in fs::read_dir(root).map_err(error)? {
+            let entry = entry.map_err(error)?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if entry.file_type().map_err(error)?.is_dir() && !name.bytes().all(|b| b.is_ascii_digit()) {
+                names.push(name);
+            }
+        }
+        names.sort();
+
+        Ok(names.iter().map(|name| Inputs::profile(root, name)).collect())
+    }
+
+    pub fn day_path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("day{day:02}.txt"))
+    }
+
+    pub fn answers_path(&self, year: u16) -> PathBuf {
+        self.dir.join(year.to_string()).join("answers.toml")
+    }
+}
+
+pub fn read_file(path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path)
+        .map_err(|error| InputError { source: path.display().to_string(), error })
+}
+
+/// Runs every part of the given [`Solution`](solution::Solution) on `input`, printing each answer
+/// with its timing, or its error to stderr.  Returns whether every part was solved.
+pub fn print_run<S: solution::Solution>(input: &str) -> bool {
+    let parts: Vec<usize> = (1..=S::PARTS).collect();
+    let run = match solution::run::<S>(input, &parts) {
+        Ok(run) => run,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    print_parse(run.parse_time);
+    let mut solved = true;
+    for part in run.parts {
+        solved &= print_part(part.part, &part.answer, part.time);
+    }
+    solved
+}
+
+/// Prints how long parsing took, as [`print_run`] does.
+pub fn print_parse(time: Duration) {
+    println!("Parse (Time: {}μs)", time.as_micros());
+}
+
+/// Prints a part's answer with its timing, or its error to stderr, as [`print_run`] does.  Returns
+/// whether the part was solved.
+pub fn print_part(part: usize, answer: &Result<solution::Answer, error::AocError>, time: Duration) -> bool {
+    match answer {
+        Ok(answer) => {
+            println!("Part {part}: {answer} (Time: {}μs)", time.as_micros());
+            true
+        },
+        Err(err) => {
+            eprintln!("Part {part}: {err}");
+            false
+        }
+    }
+}
+
+/// Generates a `main` that reads the input (see [`read_input`]) and runs every part of the given
+/// [`Solution`](solution::Solution) with [`print_run`].
 #[macro_export]
 macro_rules! build_main {
-    ($input:literal, $( $part:literal => $solver:expr),+) => {
-        use std::time::Instant;
+    ($year:literal, $day:literal, $solution:ty) => {
         fn main() {
-            let input: &str = include_str!(concat!("../input/", $input));
-            $(
-            let start = Instant::now();
-            let result = $solver(input);
-            let duration = start.elapsed().as_micros();
-            println!("{}: {} (Time: {}μs)", $part, result, duration);
-            )+
+            let input = match $crate::read_input($year, $day) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("{err}");
+                    std::process::exit(1);
+                }
+            };
+
+            if !$crate::print_run::<$solution>(&input) {
+                std::process::exit(1);
+            }
         }
     };
 }
 
+/// Generates a test for each puzzle example, checking the answers it gives for some or all parts:
+///
+/// ```ignore
+/// examples! {
+///     Day02;
+///     small_present: "2x3x4" => part1 = 58, part2 = 34;
+///     #[ignore] slow_one: "..." => part2 = 0;
+/// }
+/// ```
 #[macro_export]
-macro_rules! build_main_res {
-    ($input:literal, $( $part:literal => $solver:expr),+) => {
-        use std::time::Instant;
-        fn main() {
-            let input: &str = include_str!(concat!("../input/", $input));
-            $(
-            let start = Instant::now();
-            let result = $solver(input).unwrap();
-            let duration = start.elapsed().as_micros();
-            println!("{}: {} (Time: {}μs)", $part, result, duration);
-            )+
-        }
+macro_rules! examples {
+    ($solution:ty; $($(#[$attr:meta])* $name:ident: $input:expr => $($part:ident = $answer:expr),+;)+) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() -> Result<(), $crate::error::AocError> {
+                use $crate::solution::{Answer, Solution};
+
+                let input = <$solution as Solution>::parse($input)?;
+                $(
+                    assert_eq!(<$solution as Solution>::$part(&input)?, Answer::from($answer), stringify!($part));
+                )+
+                Ok(())
+            }
+        )+
     };
-}
\ No newline at end of file
+}
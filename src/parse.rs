@@ -0,0 +1,276 @@
+use std::str::FromStr;
+use nom::character::complete::{digit1, one_of};
+use nom::combinator::{opt, recognize};
+use nom::error::{ErrorKind, VerboseError, VerboseErrorKind};
+use nom::multi::many1;
+use nom::sequence::pair;
+use nom::Parser;
+use crate::error::AocError;
+use crate::grid::Grid;
+
+/// `nom::IResult` with an error type that records which grammar rules (named with
+/// `nom::error::context`) were being parsed when a failure happened.
+pub type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+/// Unwraps the result of running a parser over the whole of `input`, turning a nom failure (or
+/// anything but whitespace left unparsed) into an [`AocError::Parse`] pointing at the problem.
+pub fn finish<'a, O>(input: &'a str, result: IResult<&'a str, O>) -> Result<O, AocError> {
+    match result {
+        Ok((rest, output)) if rest.trim().is_empty() => Ok(output),
+        Ok((rest, _)) => {
+            let rest = rest.trim_start();
+            Err(AocError::parse(input, rest, format!("unexpected input, found {}", snippet(rest))))
+        },
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(describe(input, e)),
+        Err(nom::Err::Incomplete(_)) => Err(AocError::parse(input, "", "unexpected end of input"))
+    }
+}
+
+/// Describes a failure as "expected <rule>, found <text>", where the rule is the innermost
+/// `context` (qualified by the enclosing ones) or, failing that, whatever nom was looking for.
+fn describe(input: &str, e: VerboseError<&str>) -> AocError {
+    let Some(&(at, ref kind)) = e.errors.first() else {
+        return AocError::parse(input, "", "unknown error");
+    };
+
+    if *kind == VerboseErrorKind::Nom(ErrorKind::TooLarge) {
+        let end = at.find(|c: char| !c.is_ascii_digit() && c != '-' && c != '+').unwrap_or(at.len());
+        return AocError::parse(input, at, format!("number out of range: {}", &at[..end]));
+    }
+
+    let contexts: Vec<&str> = e.errors.iter()
+        .filter_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(c) => Some(*c),
+            _ => None
+        })
+        .collect();
+
+    let expected = if contexts.is_empty() {
+        match kind {
+            VerboseErrorKind::Char(c) => format!("{c:?}"),
+            VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+            VerboseErrorKind::Context(c) => c.to_string()
+        }
+    }
+    else {
+        contexts.join(" in ")
+    };
+
+    AocError::parse(input, at, format!("expected {expected}, found {}", snippet(at)))
+}
+
+/// The rest of the line starting at `rest`, shortened if it's long.
+fn snippet(rest: &str) -> String {
+    const MAX_CHARS: usize = 20;
+
+    let line = rest.lines().next().unwrap_or("");
+    if line.is_empty() {
+        return if rest.is_empty() { "end of input".to_owned() } else { "end of line".to_owned() };
+    }
+
+    if line.chars().count() > MAX_CHARS {
+        let prefix: String = line.chars().take(MAX_CHARS).collect();
+        format!("`{prefix}...`")
+    }
+    else {
+        format!("`{line}`")
+    }
+}
+
+/// An unsigned integer of any type, failing rather than wrapping if it's out of the type's range.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    let (rest, digits) = digit1(input)?;
+    in_range(input, rest, digits)
+}
+
+/// An integer of any type, with an optional sign.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    let (rest, digits) = recognize(pair(opt(one_of("+-")), digit1))(input)?;
+    in_range(input, rest, digits)
+}
+
+fn in_range<'a, T: FromStr>(input: &'a str, rest: &'a str, digits: &str) -> IResult<&'a str, T> {
+    match digits.parse() {
+        Ok(n) => Ok((rest, n)),
+        Err(_) => Err(nom::Err::Failure(out_of_range(input)))
+    }
+}
+
+fn out_of_range(input: &str) -> VerboseError<&str> {
+    VerboseError { errors: vec![(input, VerboseErrorKind::Nom(ErrorKind::TooLarge))] }
+}
+
+/// Every integer in `text`, ignoring whatever separates them.  A `-` directly before a number
+/// makes it negative unless it follows a digit, so ranges like `1-5` are two positive numbers.
+pub fn integers<T: FromStr>(text: &str) -> Result<Vec<T>, AocError> {
+    let bytes = text.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let negative = i > 0 && bytes[i - 1] == b'-' && (i < 2 || !bytes[i - 2].is_ascii_digit());
+        let start = if negative { i - 1 } else { i };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        let n = text[start..i].parse()
+            .map_err(|_| AocError::parse_at(text, start, format!("number out of range: {}", &text[start..i])))?;
+        result.push(n);
+    }
+
+    Ok(result)
+}
+
+/// Parses one `item` per line.  Unlike `separated_list1(newline, item)`, a line that `item` can't
+/// parse in full is an error rather than the end of the list; the list does end cleanly at a
+/// blank line or the end of the input.
+pub fn lines<'a, O, F>(mut item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where F: Parser<&'a str, O, VerboseError<&'a str>>
+{
+    move |input: &'a str| {
+        let mut result = Vec::new();
+        let mut rest = input;
+
+        loop {
+            let (after, output) = match item.parse(rest) {
+                Ok(ok) => ok,
+                Err(nom::Err::Error(e)) if !result.is_empty() => return Err(nom::Err::Failure(e)),
+                Err(e) => return Err(e)
+            };
+            result.push(output);
+
+            let next_line = after.strip_prefix("\r\n").or_else(|| after.strip_prefix('\n'));
+            match next_line {
+                None if after.is_empty() => return Ok((after, result)),
+                None => return Err(nom::Err::Failure(end_of_line_expected(after))),
+                Some(next) if next.is_empty() || next.starts_with(['\n', '\r']) => {
+                    return Ok((after, result))
+                },
+                Some(next) => rest = next
+            }
+        }
+    }
+}
+
+/// A rectangular grid with one `cell` per character, one row per line.
+pub fn grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>>
+where F: Parser<&'a str, T, VerboseError<&'a str>>
+{
+    let mut rows = lines(many1(cell));
+
+    move |input: &'a str| {
+        let (rest, grid) = rows(input)?;
+
+        let width = grid[0].len();
+        if let Some(i) = grid.iter().position(|row| row.len() != width) {
+            let row_start: usize = input.split('\n').take(i).map(|line| line.len() + 1).sum();
+            let error = VerboseError {
+                errors: vec![(&input[row_start..], VerboseErrorKind::Context("row as wide as the first"))]
+            };
+            return Err(nom::Err::Failure(error));
+        }
+
+        Ok((rest, Grid::from_rows(grid).expect("rows checked to be non-empty and as wide as each other")))
+    }
+}
+
+fn end_of_line_expected(input: &str) -> VerboseError<&str> {
+    VerboseError {
+        errors: vec![
+            (input, VerboseErrorKind::Nom(ErrorKind::CrLf)),
+            (input, VerboseErrorKind::Context("end of line"))
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::branch::alt;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::char;
+    use nom::combinator::value;
+    use nom::error::context;
+    use nom::sequence::preceded;
+    use super::*;
+
+    fn item(input: &str) -> IResult<&str, &str> {
+        context("item", preceded(tag("item "), context("number", digit1)))(input)
+    }
+
+    fn parse_error(line: usize, column: usize, message: &str) -> AocError {
+        AocError::Parse { line, column, message: message.to_owned() }
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = "item 1\nitem 22\n";
+        assert_eq!(finish(input, lines(item)(input)), Ok(vec!["1", "22"]));
+    }
+
+    #[test]
+    fn test_lines_bad_line() {
+        let input = "item 1\nitem x\nitem 3";
+        assert_eq!(
+            finish(input, lines(item)(input)),
+            Err(parse_error(2, 6, "expected number in item, found `x`"))
+        );
+    }
+
+    #[test]
+    fn test_lines_trailing_text() {
+        let input = "item 1\nitem 2 and more";
+        assert_eq!(
+            finish(input, lines(item)(input)),
+            Err(parse_error(2, 7, "expected end of line, found ` and more`"))
+        );
+    }
+
+    #[test]
+    fn test_lines_stop_at_blank_line() {
+        let input = "item 1\n\nrest";
+        assert_eq!(
+            finish(input, lines(item)(input)),
+            Err(parse_error(3, 1, "unexpected input, found `rest`"))
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let cell = || alt((value(true, char('#')), value(false, char('.'))));
+        let input = "#.\n.#\n";
+        assert_eq!(finish(input, grid(cell())(input)), Ok(Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap()));
+
+        let input = "#.\n.\n#.";
+        assert_eq!(
+            finish(input, grid(cell())(input)),
+            Err(parse_error(2, 1, "expected row as wide as the first, found `.`"))
+        );
+    }
+
+    #[test]
+    fn test_unsigned() {
+        assert_eq!(finish("255", unsigned::<u8>("255")), Ok(255));
+        assert_eq!(finish("256", unsigned::<u8>("256")), Err(parse_error(1, 1, "number out of range: 256")));
+        assert!(finish("-1", unsigned::<u32>("-1")).is_err());
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(finish("-128", signed::<i8>("-128")), Ok(-128));
+        assert_eq!(finish("+7", signed::<i64>("+7")), Ok(7));
+        assert_eq!(finish("-129", signed::<i8>("-129")), Err(parse_error(1, 1, "number out of range: -129")));
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(integers::<i32>("row 2981, column -3075."), Ok(vec![2981, -3075]));
+        assert_eq!(integers::<i32>("1-5,10"), Ok(vec![1, 5, 10]));
+        assert_eq!(integers::<u8>("a\nb 300"), Err(parse_error(2, 3, "number out of range: 300")));
+    }
+}
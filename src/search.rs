@@ -0,0 +1,327 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+use crate::cancel;
+use crate::error::AocError;
+
+/// The cost of a path, built up by adding the cost of each step along it.
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    /// The cost of the empty path.
+    const ZERO: Self;
+}
+
+macro_rules! int_costs {
+    ($($t:ty),*) => {
+        $(impl Cost for $t { const ZERO: Self = 0; })*
+    };
+}
+
+int_costs!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A search problem: a start state, the steps that can be taken from each state and what they
+/// cost, and which states are goals.
+pub trait Problem {
+    type State: Clone;
+    type Cost: Cost;
+
+    fn start(&self) -> Self::State;
+
+    /// The states one step on from `state`, each with the cost of getting there.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, Self::Cost)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A guess at the cost still to come from `state` to a goal, used by [`astar`] and
+    /// [`branch_and_bound`] to skip hopeless states.  It must be optimistic, never more than the
+    /// true cost when minimising and never less when maximising.  The default of zero is only
+    /// optimistic when minimising.
+    fn estimate(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::ZERO
+    }
+}
+
+/// A path to a goal: its states from the start to the goal, and its total cost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    pub path: Vec<S>
+}
+
+/// What a search gives: the best path to a goal, if there is one.
+pub type Outcome<P> = Result<Option<Found<<P as Problem>::State, <P as Problem>::Cost>>, AocError>;
+
+/// Whether [`branch_and_bound`] is after the cheapest path or the dearest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Objective { Minimise, Maximise }
+
+impl Objective {
+    fn better<C: Ord>(self, a: C, b: C) -> bool {
+        match self {
+            Objective::Minimise => a < b,
+            Objective::Maximise => a > b
+        }
+    }
+}
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parent: Option<usize>
+}
+
+/// Every state reached so far, each with the best known way there.
+struct Tree<S, C> {
+    nodes: Vec<Node<S, C>>,
+    index: HashMap<S, usize>
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Tree<S, C> {
+    fn new(start: S) -> Tree<S, C> {
+        let mut tree = Tree { nodes: Vec::new(), index: HashMap::new() };
+        tree.index.insert(start.clone(), 0);
+        tree.nodes.push(Node { state: start, cost: C::ZERO, parent: None });
+        tree
+    }
+
+    /// Records reaching `state` from node `parent` at `cost`, unless it's been reached before.
+    /// Gives its node if so.
+    fn reach(&mut self, state: S, cost: C, parent: usize) -> Option<usize> {
+        if self.index.contains_key(&state) {
+            return None;
+        }
+
+        let i = self.nodes.len();
+        self.index.insert(state.clone(), i);
+        self.nodes.push(Node { state, cost, parent: Some(parent) });
+        Some(i)
+    }
+
+    /// Records reaching `state` from node `parent` at `cost`, unless it's been reached before at
+    /// least as cheaply.  Gives its node if so.
+    fn improve(&mut self, state: S, cost: C, parent: usize) -> Option<usize> {
+        match self.index.get(&state) {
+            Some(&i) if self.nodes[i].cost <= cost => None,
+            Some(&i) => {
+                self.nodes[i].cost = cost;
+                self.nodes[i].parent = Some(parent);
+                Some(i)
+            },
+            None => self.reach(state, cost, parent)
+        }
+    }
+
+    fn found(&self, goal: usize) -> Found<S, C> {
+        let mut path = Vec::new();
+        let mut node = Some(goal);
+        while let Some(i) = node {
+            path.push(self.nodes[i].state.clone());
+            node = self.nodes[i].parent;
+        }
+        path.reverse();
+
+        Found { cost: self.nodes[goal].cost, path }
+    }
+}
+
+/// The path to a goal with the fewest steps, whatever they cost.
+pub fn bfs<P>(problem: &P) -> Outcome<P>
+where P: Problem, P::State: Eq + Hash
+{
+    let mut tree = Tree::new(problem.start());
+    let mut queue = VecDeque::from([0]);
+
+    let mut steps = 0;
+    while let Some(i) = queue.pop_front() {
+        cancel::tick(steps)?;
+        steps += 1;
+        if problem.is_goal(&tree.nodes[i].state) {
+            return Ok(Some(tree.found(i)));
+        }
+
+        for (next, step) in problem.successors(&tree.nodes[i].state) {
+            let cost = tree.nodes[i].cost + step;
+            queue.extend(tree.reach(next, cost, i));
+        }
+    }
+
+    Ok(None)
+}
+
+/// The cheapest path to a goal.  Step costs mustn't be negative.
+pub fn dijkstra<P>(problem: &P) -> Outcome<P>
+where P: Problem, P::State: Eq + Hash
+{
+    best_first(problem, |_| P::Cost::ZERO)
+}
+
+/// The cheapest path to a goal, like [`dijkstra`] but searching towards the goal first as guided
+/// by the problem's [`estimate`](Problem::estimate).
+pub fn astar<P>(problem: &P) -> Outcome<P>
+where P: Problem, P::State: Eq + Hash
+{
+    best_first(problem, |state| problem.estimate(state))
+}
+
+fn best_first<P>(problem: &P, estimate: impl Fn(&P::State) -> P::Cost) -> Outcome<P>
+where P: Problem, P::State: Eq + Hash
+{
+    let start = problem.start();
+    let mut heap = BinaryHeap::from([Reverse((estimate(&start), P::Cost::ZERO, 0))]);
+    let mut tree = Tree::new(start);
+
+    let mut steps = 0;
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        cancel::tick(steps)?;
+        steps += 1;
+        // A cheaper way here turned up after this entry was queued.
+        if cost > tree.nodes[i].cost {
+            continue;
+        }
+        if problem.is_goal(&tree.nodes[i].state) {
+            return Ok(Some(tree.found(i)));
+        }
+
+        for (next, step) in problem.successors(&tree.nodes[i].state) {
+            let next_cost = cost + step;
+            let guess = next_cost + estimate(&next);
+            if let Some(j) = tree.improve(next, next_cost, i) {
+                heap.push(Reverse((guess, next_cost, j)));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// The best path to a goal by `objective`, trying every path depth first but skipping any that
+/// the problem's [`estimate`](Problem::estimate) says can't beat the best found so far.  Nothing
+/// is searched past a goal, and states aren't remembered, so the paths must all come to an end.
+pub fn branch_and_bound<P: Problem>(problem: &P, objective: Objective) -> Outcome<P> {
+    let mut best: Option<Found<P::State, P::Cost>> = None;
+    let mut path = Vec::new();
+    let mut stack = vec![(problem.start(), P::Cost::ZERO, 0)];
+
+    let mut steps = 0;
+    while let Some((state, cost, depth)) = stack.pop() {
+        cancel::tick(steps)?;
+        steps += 1;
+        if best.as_ref().is_some_and(|best| !objective.better(cost + problem.estimate(&state), best.cost)) {
+            continue;
+        }
+
+        path.truncate(depth);
+        path.push(state.clone());
+        if problem.is_goal(&state) {
+            if best.as_ref().is_none_or(|best| objective.better(cost, best.cost)) {
+                best = Some(Found { cost, path: path.clone() });
+            }
+            continue;
+        }
+
+        // Pushed in reverse, so that they're tried in the order given.
+        for (next, step) in problem.successors(&state).into_iter().rev() {
+            stack.push((next, cost + step, depth + 1));
+        }
+    }
+
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Grid, Pos, ORTHOGONAL};
+    use super::*;
+
+    /// A walk across a grid of digits from the top left to the bottom left, each step costing the
+    /// digit stepped onto.  `#`s are walls.
+    struct Walk(Grid<char>);
+
+    impl Problem for Walk {
+        type State = Pos;
+        type Cost = u32;
+
+        fn start(&self) -> Pos {
+            (0, 0)
+        }
+
+        fn successors(&self, &pos: &Pos) -> Vec<(Pos, u32)> {
+            self.0.neighbors(pos, &ORTHOGONAL)
+                .filter_map(|p| self.0[p].to_digit(10).map(|cost| (p, cost)))
+                .collect()
+        }
+
+        fn is_goal(&self, &(i, j): &Pos) -> bool {
+            (i, j) == (self.0.rows() as isize - 1, 0)
+        }
+
+        fn estimate(&self, &(i, j): &Pos) -> u32 {
+            (self.0.rows() as isize - 1 - i + j) as u32
+        }
+    }
+
+    fn walk(map: &str) -> Walk {
+        Walk(Grid::from_rows(map.lines().map(|line| line.chars().collect()).collect()).unwrap())
+    }
+
+    #[test]
+    fn test_shortest_paths() -> Result<(), AocError> {
+        let around = walk("\
+1111
+9##1
+1111");
+
+        let fewest_steps = bfs(&around)?.unwrap();
+        assert_eq!(fewest_steps.path, [(0, 0), (1, 0), (2, 0)]);
+        assert_eq!(fewest_steps.cost, 10);
+
+        let cheapest = dijkstra(&around)?.unwrap();
+        assert_eq!(cheapest.cost, 8);
+        assert_eq!(cheapest.path, [(0, 0), (0, 1), (0, 2), (0, 3), (1, 3), (2, 3), (2, 2), (2, 1), (2, 0)]);
+        assert_eq!(astar(&around)?, Some(cheapest));
+
+        assert_eq!(dijkstra(&walk("11\n##\n11"))?, None);
+        Ok(())
+    }
+
+    /// Packing items of the given weights and values into a bag that holds up to a total weight.
+    struct Knapsack { items: Vec<(u32, u32)>, capacity: u32 }
+
+    impl Problem for Knapsack {
+        /// How many items have been considered, and their total weight so far.
+        type State = (usize, u32);
+        type Cost = u32;
+
+        fn start(&self) -> (usize, u32) {
+            (0, 0)
+        }
+
+        fn successors(&self, &(i, weight): &(usize, u32)) -> Vec<((usize, u32), u32)> {
+            let (item_weight, value) = self.items[i];
+            let mut next = vec![((i + 1, weight), 0)];
+            if weight + item_weight <= self.capacity {
+                next.push(((i + 1, weight + item_weight), value));
+            }
+            next
+        }
+
+        fn is_goal(&self, &(i, _): &(usize, u32)) -> bool {
+            i == self.items.len()
+        }
+
+        fn estimate(&self, &(i, _): &(usize, u32)) -> u32 {
+            self.items[i..].iter().map(|&(_, value)| value).sum()
+        }
+    }
+
+    #[test]
+    fn test_branch_and_bound() -> Result<(), AocError> {
+        let knapsack = Knapsack { items: vec![(5, 10), (4, 40), (6, 30), (3, 50)], capacity: 10 };
+
+        let best = branch_and_bound(&knapsack, Objective::Maximise)?.unwrap();
+        assert_eq!(best.cost, 90);
+        assert_eq!(best.path.last(), Some(&(4, 7)));
+        assert_eq!(branch_and_bound(&knapsack, Objective::Minimise)?.unwrap().cost, 0);
+        Ok(())
+    }
+}
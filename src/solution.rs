@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+use crate::error::AocError;
+
+/// The answer to one part of a puzzle.  Every day's solvers return this, whatever the natural type
+/// of their result, so that answers can be displayed and compared uniformly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String)
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}")
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),+) => {
+        $(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as i128)
+            }
+        }
+        )+
+    };
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        i128::try_from(n)
+            .map(Answer::Number)
+            .unwrap_or_else(|_| Answer::Text(n.to_string()))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
+    }
+}
+
+/// A day's puzzle: parsing the input once, then solving each part from the parsed form.
+pub trait Solution {
+    /// The parsed puzzle input, which may borrow from the raw text.
+    type Input<'a>;
+
+    /// Number of parts the puzzle has; only day 25 has fewer than two.
+    const PARTS: usize = 2;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError>;
+
+    /// Puzzles with `PARTS == 1` have nothing to solve here.
+    fn part2(_input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Err(AocError::NoSolution)
+    }
+}
+
+pub struct PartRun {
+    pub part: usize,
+    pub answer: Result<Answer, AocError>,
+    pub time: Duration
+}
+
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>
+}
+
+/// Parses `input` and solves the requested `parts` (1-based), timing each step separately.  Fails
+/// only if the input can't be parsed; errors solving a part are reported in its [`PartRun`].
+pub fn run<S: Solution>(input: &str, parts: &[usize]) -> Result<Run, AocError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts.iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed),
+                2 => S::part2(&parsed),
+                _ => Err(AocError::NoSuchPart(part))
+            };
+            PartRun { part, answer, time: start.elapsed() }
+        })
+        .collect();
+
+    Ok(Run { parse_time, parts })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::y2015::day01::Day01;
+    use super::*;
+
+    #[test]
+    fn test_run() -> Result<(), AocError> {
+        let run = run::<Day01>("(()", &[3, 1])?;
+        let answers: Vec<_> = run.parts.into_iter().map(|part| (part.part, part.answer)).collect();
+        assert_eq!(answers, [(3, Err(AocError::NoSuchPart(3))), (1, Ok(1.into()))]);
+        Ok(())
+    }
+}